@@ -0,0 +1,49 @@
+//! Compares unit selection against the comparison ladder it replaced.
+//!
+//! Run with `cargo bench --bench unit_selection`. `tests/properties.rs` checks
+//! that both pick the same units.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use repr_size::{Size, Units};
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use common::ladder;
+
+/// Byte counts spread across every unit, from an xorshift generator so runs are repeatable.
+fn inputs() -> Vec<u64> {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    (0..10_000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state >> (state % 64)
+        })
+        .collect()
+}
+
+fn unit_selection(c: &mut Criterion) {
+    let inputs = inputs();
+    let mut group = c.benchmark_group("unit_selection");
+    let mut bench = |name: &str, pick: &dyn Fn(u64) -> Units| {
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                for &bytes in &inputs {
+                    black_box(pick(black_box(bytes)));
+                }
+            })
+        });
+    };
+    bench("decimal ladder", &|bytes| ladder(Units::decimal(), bytes as u128));
+    bench("decimal", &|bytes| Size::from(bytes).get_decimal_unit());
+    bench("binary ladder", &|bytes| ladder(Units::binary(), bytes as u128));
+    bench("binary", &|bytes| Size::from(bytes).get_binary_unit());
+    group.finish();
+}
+
+criterion_group!(benches, unit_selection);
+criterion_main!(benches);
@@ -0,0 +1,253 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::parse::{parse_size, ParseSizeError};
+use crate::{format, Size, Units};
+
+/// A signed difference between two sizes, such as how much a cache grew or shrank.
+/// Create these by using `Size::delta(Size)`.
+///
+/// Positive deltas are displayed with a leading `+`, negative ones with a `-`.
+/// A zero delta prints as `"0 B"` with no sign, unless the `+` flag is given.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// # if cfg!(feature = "default-binary") { return; }
+/// let before = Size::from(2_500_000u64);
+/// let after = Size::from(3_700_000u64);
+///
+/// println!("{}", after.delta(before)); // "+1.2 MB"
+/// # assert_eq!(after.delta(before).to_string(), "+1.2 MB");
+/// println!("{}", before.delta(after)); // "-1.2 MB"
+/// # assert_eq!(before.delta(after).to_string(), "-1.2 MB");
+/// # assert_eq!(Size::from(0u64).delta(Size::from(340_000u64)).to_string(), "-340.0 kB");
+/// println!("{}", after.delta(after)); // "0 B"
+/// # assert_eq!(after.delta(after).to_string(), "0 B");
+/// println!("{:+}", after.delta(after)); // "+0 B"
+/// # assert_eq!(format!("{:+}", after.delta(after)), "+0 B");
+/// println!("{:.2}", before.delta(after)); // "-1.20 MB"
+/// # assert_eq!(format!("{:.2}", before.delta(after)), "-1.20 MB");
+/// println!("{:#}", before.delta(after)); // "-1.1 MiB"
+/// # assert_eq!(format!("{:#}", before.delta(after)), "-1.1 MiB");
+/// # assert_eq!(format!("{:#.3}", after.delta(before)), "+1.144 MiB");
+///
+/// // The sign is padded along with the size.
+/// assert_eq!(format!("[{:>10}]", before.delta(after)), "[   -1.2 MB]");
+/// assert_eq!(format!("[{:*<+8}]", after.delta(after)), "[+0 B****]");
+/// # assert_eq!(format!("[{:>+10}]", after.delta(before)), "[   +1.2 MB]");
+/// # assert_eq!(format!("[{:^10}]", after.delta(before)), "[ +1.2 MB  ]");
+/// # assert_eq!(format!("[{:>8}]", after.delta(after)), "[     0 B]");
+/// # assert_eq!(format!("[{:+>10.2}]", before.delta(after)), "[++-1.20 MB]");
+/// # assert_eq!(format!("[{:>+#12}]", after.delta(before)), "[    +1.1 MiB]");
+///
+/// assert!(before.delta(after).is_negative());
+/// assert_eq!(before.delta(after).abs(), Size::from(1_200_000u64));
+/// # assert_eq!(Size::from(0u64).delta(Size::from(u64::MAX)).abs(), Size::from(u64::MAX));
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct SizeDelta(i128);
+
+impl SizeDelta {
+    /// Returns the magnitude of the delta, ignoring its sign.
+    pub fn abs(&self) -> Size {
+        Size(self.0.unsigned_abs() as u64)
+    }
+
+    /// Returns true if the delta represents a decrease in size.
+    pub fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    /// Returns a string representation of the delta using
+    /// the given unit of bytes, prefixed with its sign.
+    /// ```rust
+    /// # use repr_size::*;
+    /// let shrunk = Size::from(1000u64).delta(Size::from(23_000u64));
+    ///
+    /// println!("{}", shrunk.repr(Units::Bytes)); // "-22000 B"
+    /// # assert_eq!(shrunk.repr(Units::Bytes), "-22000 B");
+    /// println!("{}", shrunk.repr(Units::Kibibytes)); // "-21.5 KiB"
+    /// # assert_eq!(shrunk.repr(Units::Kibibytes), "-21.5 KiB");
+    /// ```
+    pub fn repr(&self, unit: Units) -> String {
+        format!("{}{}", self.sign(false), self.abs().repr(unit))
+    }
+
+    fn sign(&self, force: bool) -> &'static str {
+        match self.0 {
+            x if x < 0 => "-",
+            x if x > 0 || force => "+",
+            _ => "",
+        }
+    }
+}
+
+impl Display for SizeDelta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The sign goes inside the padding, so it is written along with the size.
+        let sign = self.sign(f.sign_plus());
+        if self.0 == 0 {
+            return format::pad(f, format_args!("{}0 B", sign));
+        }
+        let precision = f.precision().unwrap_or(1);
+        if f.alternate() {
+            format::pad(f, format_args!("{}{:#.*}", sign, precision, self.abs()))
+        } else {
+            format::pad(f, format_args!("{}{:.*}", sign, precision, self.abs()))
+        }
+    }
+}
+
+impl FromStr for SizeDelta {
+    type Err = ParseSizeError;
+
+    /// Parses a size with an optional leading sign, such as `"-340.0 kB"` or `"+1.2 MB"`.
+    /// ```rust
+    /// # use repr_size::*;
+    /// let delta: SizeDelta = "-340.0 kB".parse().unwrap();
+    ///
+    /// assert!(delta.is_negative());
+    /// assert_eq!(delta.abs(), Size::from(340_000u64));
+    /// # assert_eq!("+1.2 MB".parse::<SizeDelta>().unwrap().abs(), Size::from(1_200_000u64));
+    /// # assert!(!"1.2 MB".parse::<SizeDelta>().unwrap().is_negative());
+    /// # assert_eq!("0 B".parse::<SizeDelta>().unwrap().to_string(), "0 B");
+    /// # assert!("-".parse::<SizeDelta>().is_err());
+    /// # assert!("--1 B".parse::<SizeDelta>().is_err());
+    /// # assert_eq!("+100 Mbit".parse::<SizeDelta>().unwrap().abs(), Size::from(12_500_000u64));
+    /// # assert_eq!("100 Mb".parse::<SizeDelta>().unwrap().abs(), Size::from(12_500_000u64));
+    /// # assert_eq!("100 MB".parse::<SizeDelta>().unwrap().abs(), Size::from(100_000_000u64));
+    /// # assert_eq!("1.5 Kibit".parse::<SizeDelta>().unwrap().abs(), Size::from(192u64));
+    /// # assert!("100 mb".parse::<SizeDelta>().is_err());
+    /// # assert_eq!("-54.2kB".parse::<SizeDelta>().unwrap().abs(), Size::from(54_200u64));
+    /// # assert_eq!("-54.2\u{202F}kB".parse::<SizeDelta>().unwrap().abs(), Size::from(54_200u64));
+    /// # assert_eq!("-54.2\u{A0}kB".parse::<SizeDelta>().unwrap().abs(), Size::from(54_200u64));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim_start();
+        let (negative, rest) = match trimmed.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+        };
+        let magnitude = parse_size(rest).map_err(|err| err.offset_by(s.len() - rest.len()))?.0 as i128;
+        Ok(Self(if negative { -magnitude } else { magnitude }))
+    }
+}
+
+impl From<Size> for SizeDelta {
+    fn from(rhs: Size) -> Self {
+        Self(rhs.0 as i128)
+    }
+}
+
+impl From<SizeDelta> for i128 {
+    fn from(rhs: SizeDelta) -> Self {
+        rhs.0
+    }
+}
+
+/// How a size changed relative to a previous size, as both a signed delta and a percentage.
+/// Create these by using `Size::change_from(Size)`.
+///
+/// Displays as the delta followed by the percentage change, such as
+/// `"+700.0 kB (+16.7%)"`. The percentage shows as `"n/a"` when the previous size
+/// was zero, since any growth from nothing is infinite.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// # if cfg!(feature = "default-binary") { return; }
+/// let before = Size::from(4_200_000u64);
+/// let after = Size::from(4_900_000u64);
+///
+/// println!("{}", after.change_from(before)); // "+700.0 kB (+16.7%)"
+/// # assert_eq!(after.change_from(before).to_string(), "+700.0 kB (+16.7%)");
+/// println!("{}", before.change_from(after)); // "-700.0 kB (-14.3%)"
+/// # assert_eq!(before.change_from(after).to_string(), "-700.0 kB (-14.3%)");
+/// println!("{}", after.change_from(after)); // "+0 B (0.0%)"
+/// # assert_eq!(after.change_from(after).to_string(), "+0 B (0.0%)");
+/// println!("{}", after.change_from(Size::ZERO)); // "+4.9 MB (n/a)"
+/// # assert_eq!(after.change_from(Size::ZERO).to_string(), "+4.9 MB (n/a)");
+/// # assert_eq!(Size::ZERO.change_from(Size::ZERO).to_string(), "+0 B (n/a)");
+/// # let big = Size::from_units(8, Units::Gigabytes);
+/// # let bigger = big + Size::from(8u64);
+/// # assert_eq!(bigger.change_from(big).to_string(), "+8 B (+0.0%)");
+/// # assert!((bigger.change_from(big).percent().unwrap() - 1e-7).abs() < 1e-20);
+/// ```
+#[cfg(feature = "float")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SizeChange {
+    delta: SizeDelta,
+    percent: Option<f64>,
+}
+
+#[cfg(feature = "float")]
+impl SizeChange {
+    /// Returns the signed difference from the previous size.
+    pub fn delta(&self) -> SizeDelta {
+        self.delta
+    }
+
+    /// Returns the change as a percentage of the previous size, or `None` if the
+    /// previous size was zero.
+    pub fn percent(&self) -> Option<f64> {
+        self.percent
+    }
+}
+
+#[cfg(feature = "float")]
+impl Display for SizeChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:+} ", self.delta)?;
+        match self.percent {
+            None => write!(f, "(n/a)"),
+            Some(0.0) => write!(f, "(0.0%)"),
+            Some(percent) => write!(f, "({:+.1}%)", percent),
+        }
+    }
+}
+
+impl Size {
+    /// Returns the signed difference `self - other`, which is negative when
+    /// `other` is larger.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// # if cfg!(feature = "default-binary") { return; }
+    /// let cache_before = Size::from(5_000u64);
+    /// let cache_after = Size::from(3_000u64);
+    ///
+    /// let change = cache_after.delta(cache_before);
+    /// assert!(change.is_negative());
+    /// assert_eq!(change.to_string(), "-2.0 kB");
+    /// ```
+    pub fn delta(&self, other: Size) -> SizeDelta {
+        SizeDelta(self.0 as i128 - other.0 as i128)
+    }
+
+    /// Returns how this size changed relative to `previous`.
+    ///
+    /// The percentage is computed in `f64`, so it stays precise for
+    /// multi-gigabyte sizes.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let old_binary = Size::from(4_200_000u64);
+    /// let new_binary = Size::from(4_900_000u64);
+    ///
+    /// let change = new_binary.change_from(old_binary);
+    /// println!("binary grew from {} to {} ({})", old_binary, new_binary, change);
+    /// assert_eq!(change.delta(), new_binary.delta(old_binary));
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn change_from(&self, previous: Size) -> SizeChange {
+        let delta = self.delta(previous);
+        let percent = match previous.0 {
+            0 => None,
+            previous => Some(delta.0 as f64 / previous as f64 * 100.0),
+        };
+        SizeChange { delta, percent }
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt::Display;
+
+/// The error returned when a signed integer cannot be converted into a `Size`.
+///
+/// Both variants carry the offending value so it can be logged.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// assert_eq!(Size::try_from(-1i64), Err(SizeConversionError::Negative(-1)));
+/// assert_eq!(
+///     Size::try_from(-1i64).unwrap_err().to_string(),
+///     "byte sizes cannot be negative, got -1",
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SizeConversionError {
+    /// The value was below zero.
+    Negative(i128),
+    /// The value was larger than `Size::MAX`.
+    TooLarge(i128),
+}
+
+impl Display for SizeConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Negative(x) => write!(f, "byte sizes cannot be negative, got {}", x),
+            Self::TooLarge(x) => write!(
+                f,
+                "{} bytes is larger than the maximum size of {} bytes",
+                x,
+                u64::MAX
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SizeConversionError {}
+
+/// The error returned when a float cannot be converted into a `Size`.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// assert_eq!(Size::try_from_f64(f64::NAN), Err(FloatSizeError::NaN));
+/// assert_eq!(
+///     Size::try_from_f64(-1.5).unwrap_err().to_string(),
+///     "byte sizes cannot be negative, got -1.5",
+/// );
+/// ```
+#[cfg(feature = "float")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FloatSizeError {
+    /// The value was NaN.
+    NaN,
+    /// The value was positive or negative infinity.
+    Infinite,
+    /// The value was below zero.
+    Negative(f64),
+    /// The value was larger than `Size::MAX` after rounding.
+    TooLarge(f64),
+}
+
+#[cfg(feature = "float")]
+impl Display for FloatSizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NaN => write!(f, "byte sizes cannot be NaN"),
+            Self::Infinite => write!(f, "byte sizes cannot be infinite"),
+            Self::Negative(x) => write!(f, "byte sizes cannot be negative, got {}", x),
+            Self::TooLarge(x) => write!(
+                f,
+                "{} bytes is larger than the maximum size of {} bytes",
+                x,
+                u64::MAX
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "float")]
+impl std::error::Error for FloatSizeError {}
@@ -0,0 +1,1679 @@
+use std::fmt::{Alignment, Display, Write};
+use std::ops::RangeBounds;
+
+#[cfg(feature = "i18n")]
+use crate::Locale;
+use crate::{index_in, Base, Size, Units, DEFAULT_BASE};
+
+/// How a formatted size names its unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitStyle {
+    /// The unit's symbol, such as "MB". This is what `Display` uses.
+    Symbol,
+    /// The unit's full name, pluralized to match the number, such as "megabytes".
+    Long,
+}
+
+/// A `SizeFormatter` preset whose rules replace some of the usual options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Preset {
+    None,
+    /// Explorer's rounding and JEDEC unit names, from `SizeFormatter::windows_explorer`.
+    WindowsExplorer,
+    /// GNU coreutils' one-letter suffixes, from `SizeFormatter::gnu`.
+    Gnu,
+}
+
+/// What goes between the number and the unit of a formatted size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Separator {
+    /// A plain ASCII space, as in "54.2 kB". This is the default.
+    Space,
+    /// A narrow no-break space (U+202F), which is the typographically correct choice.
+    NarrowNbsp,
+    /// Nothing at all, as in "54.2kB".
+    None,
+    /// Any other string.
+    Custom(&'static str),
+}
+
+impl Separator {
+    /// Returns the text this separator writes.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Separator::Space.as_str(), " ");
+    /// assert_eq!(Separator::NarrowNbsp.as_str(), "\u{202F}");
+    /// assert_eq!(Separator::Custom("_").as_str(), "_");
+    /// ```
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Space => " ",
+            Self::NarrowNbsp => "\u{202F}",
+            Self::None => "",
+            Self::Custom(separator) => separator,
+        }
+    }
+}
+
+/// How a formatted number is rounded to the decimals shown.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let tie = Size::from(1250u64);
+///
+/// assert_eq!(tie.repr_with_rounding(Units::Kilobytes, 1, Rounding::HalfUp), "1.3 kB");
+/// assert_eq!(tie.repr_with_rounding(Units::Kilobytes, 1, Rounding::HalfEven), "1.2 kB");
+/// assert_eq!(tie.repr_with_rounding(Units::Kilobytes, 1, Rounding::Floor), "1.2 kB");
+/// assert_eq!(Size::from(1201u64).repr_with_rounding(Units::Kilobytes, 1, Rounding::Ceil), "1.3 kB");
+/// # let modes = [Rounding::HalfUp, Rounding::HalfEven, Rounding::Floor, Rounding::Ceil];
+/// # let cases: [(u64, [&str; 4]); 6] = [
+/// #     (1250, ["1.3", "1.2", "1.2", "1.3"]),
+/// #     (1350, ["1.4", "1.4", "1.3", "1.4"]),
+/// #     (1249, ["1.2", "1.2", "1.2", "1.3"]),
+/// #     (1251, ["1.3", "1.3", "1.2", "1.3"]),
+/// #     (1200, ["1.2", "1.2", "1.2", "1.2"]),
+/// #     (1999, ["2.0", "2.0", "1.9", "2.0"]),
+/// # ];
+/// # for (bytes, expected) in cases {
+/// #     for (mode, expected) in modes.iter().zip(expected) {
+/// #         assert_eq!(Size::from(bytes).repr_with_rounding(Units::Kilobytes, 1, *mode), format!("{} kB", expected));
+/// #     }
+/// # }
+/// # assert_eq!(Size::from(2500u64).repr_with_rounding(Units::Kilobytes, 0, Rounding::HalfEven), "2 kB");
+/// # assert_eq!(Size::from(3500u64).repr_with_rounding(Units::Kilobytes, 0, Rounding::HalfEven), "4 kB");
+/// # assert_eq!(Size::from(2001u64).repr_with_rounding(Units::Kilobytes, 0, Rounding::Ceil), "3 kB");
+/// # assert_eq!(Size::from(2999u64).repr_with_rounding(Units::Kilobytes, 0, Rounding::Floor), "2 kB");
+/// # assert_eq!(Size::MAX.repr_with_rounding(Units::Exbibytes, 3, Rounding::Floor), "15.999 EiB");
+/// # assert_eq!(Size::MAX.repr_with_rounding(Units::Exbibytes, 3, Rounding::Ceil), "16.000 EiB");
+/// # assert_eq!(Size::ZERO.repr_with_rounding(Units::Megabytes, 1, Rounding::Ceil), "0 B");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds to the nearest value, and ties away from zero. This is the default.
+    HalfUp,
+    /// Rounds to the nearest value, and ties to an even last digit.
+    HalfEven,
+    /// Rounds down, so the number shown is never more than the size, as storage
+    /// vendors do.
+    Floor,
+    /// Rounds up, so the number shown is never less than the size, as `du` does.
+    Ceil,
+}
+
+/// Formats sizes with a configurable unit family, precision and unit style.
+/// Create these by using `SizeFormatter::new()` and chaining options.
+///
+/// The number is computed with integer math rather than floats, so it is exact
+/// for every size and rounds half up at the last shown decimal, unless another
+/// `Rounding` is chosen.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let words = SizeFormatter::new().unit_style(UnitStyle::Long);
+///
+/// assert_eq!(words.format(Size::from(2_300_000u64)), "2.3 megabytes");
+/// assert_eq!(words.format(Size::MB), "1.0 megabyte");
+/// assert_eq!(words.base(Base::Binary).format(Size::MIB), "1.0 mebibyte");
+/// # assert_eq!(SizeFormatter::new().format(Size::from(2_300_000u64)), "2.3 MB");
+/// # assert_eq!(SizeFormatter::new().base(Base::Binary).format(Size::from(2300u64)), "2.2 KiB");
+/// # assert_eq!(words.format(Size::ZERO), "0 bytes");
+/// # assert_eq!(SizeFormatter::new().format(Size::ZERO), "0 B");
+///
+/// let precise = SizeFormatter::new().precision(3);
+/// assert_eq!(precise.format(Size::from(2_345_678u64)), "2.346 MB");
+/// assert_eq!(precise.base(Base::Binary).format(Size::from(2_345_678u64)), "2.237 MiB");
+/// assert_eq!(precise.precision(0).format(Size::from(2_500_000u64)), "3 MB");
+/// # assert_eq!(precise.unit_style(UnitStyle::Long).format(Size::from(1_000_400u64)), "1.000 megabyte");
+/// # assert_eq!(precise.unit_style(UnitStyle::Long).format(Size::from(1_000_500u64)), "1.001 megabytes");
+/// # assert_eq!(precise.precision(0).unit_style(UnitStyle::Long).format(Size::from(1_499_999u64)), "1 megabyte");
+/// ```
+///
+/// Zeros at the end of the number can be dropped after rounding, either only when
+/// the whole fraction is zero or always:
+/// ```rust
+/// # use repr_size::*;
+/// let two_decimals = SizeFormatter::new().precision(2);
+/// let whole = two_decimals.trim_zero_fraction(true);
+/// let trimmed = two_decimals.trim_zeros(true);
+///
+/// assert_eq!(whole.format(Size::from(2_000_000u64)), "2 MB");
+/// assert_eq!(whole.format(Size::from(2_500_000u64)), "2.50 MB");
+/// assert_eq!(trimmed.format(Size::from(2_500_000u64)), "2.5 MB");
+/// assert_eq!(trimmed.format(Size::from(2_000_000u64)), "2 MB");
+/// # assert_eq!(whole.format(Size::from(1_999_999u64)), "2 MB");
+/// # assert_eq!(whole.format(Size::from(2_004_999u64)), "2 MB");
+/// # assert_eq!(whole.format(Size::from(2_005_000u64)), "2.01 MB");
+/// # assert_eq!(trimmed.format(Size::from(2_010_000u64)), "2.01 MB");
+/// # assert_eq!(trimmed.format(Size::from(2_099_999u64)), "2.1 MB");
+/// # assert_eq!(trimmed.precision(5).format(Size::from(1_999_999_999u64)), "2 GB");
+/// # assert_eq!(trimmed.precision(5).format(Size::from(1_999_990_000u64)), "1.99999 GB");
+/// # assert_eq!(trimmed.precision(0).format(Size::from(1_500_000u64)), "2 MB");
+/// # assert_eq!(whole.precision(3).format(Size::from(1001u64)), "1.001 kB");
+/// # assert_eq!(whole.format(Size::from(1001u64)), "1 kB");
+/// # assert_eq!(trimmed.format(Size::ZERO), "0 B");
+/// # assert_eq!(trimmed.unit_style(UnitStyle::Long).format(Size::from(1_000_001u64)), "1 megabyte");
+/// ```
+///
+/// In exact integer mode, sizes that are a whole multiple of their unit print
+/// with no decimals, while any other size keeps them, even when they round to zero:
+/// ```rust
+/// # use repr_size::*;
+/// let exact = SizeFormatter::new().exact_integers(true);
+///
+/// assert_eq!(exact.format_as(Size::from(2048u64), Units::Kibibytes), "2 KiB");
+/// assert_eq!(exact.format_as(Size::from(2047u64), Units::Kibibytes), "2.0 KiB");
+/// assert_eq!(exact.format_as(Size::from(2560u64), Units::Kibibytes), "2.5 KiB");
+/// assert_eq!(exact.format(Size::from(999u64)), "999 B");
+/// # assert_eq!(exact.format_as(Size::from(2049u64), Units::Kibibytes), "2.0 KiB");
+/// # assert_eq!(SizeFormatter::new().trim_zero_fraction(true).format_as(Size::from(2047u64), Units::Kibibytes), "2 KiB");
+/// # for unit in [Units::Kilobytes, Units::Mebibytes, Units::Gigabytes, Units::Exbibytes] {
+/// #     let multiple = Size::from_units(3, unit);
+/// #     assert_eq!(exact.format_as(multiple, unit), format!("3 {}", unit));
+/// #     assert_eq!(exact.format_as(multiple + Size::from(1u64), unit), format!("3.0 {}", unit));
+/// #     assert_eq!(exact.format_as(multiple - Size::from(1u64), unit), format!("3.0 {}", unit));
+/// #     let base = if unit.is_binary() { Base::Binary } else { Base::Decimal };
+/// #     assert_eq!(exact.base(base).format(multiple), format!("3 {}", unit));
+/// # }
+/// # assert_eq!(exact.precision(3).format_as(Size::from(1_000_999u64), Units::Bytes), "1000999 B");
+/// # assert_eq!(exact.zero_as_bytes(false).format_as(Size::ZERO, Units::Mebibytes), "0 MiB");
+/// ```
+///
+/// The separator between the number and the unit can be changed too:
+/// ```rust
+/// # use repr_size::*;
+/// let size = Size::from(54_222u64);
+///
+/// assert_eq!(SizeFormatter::new().separator(Separator::None).format(size), "54.2kB");
+/// assert_eq!(
+///     SizeFormatter::new().separator(Separator::NarrowNbsp).format(size).as_bytes(),
+///     b"54.2\xE2\x80\xAFkB",
+/// );
+/// assert_eq!(SizeFormatter::new().separator(Separator::Custom("-")).format(size), "54.2-kB");
+/// # assert_eq!(SizeFormatter::new().separator(Separator::Space).format(size), size.to_decimal_string());
+/// # assert_eq!(SizeFormatter::new().separator(Separator::None).format(Size::ZERO), "0B");
+/// # assert_eq!(
+/// #     SizeFormatter::new().separator(Separator::None).unit_style(UnitStyle::Long).format_as(size, Units::Bytes),
+/// #     "54222bytes",
+/// # );
+/// ```
+///
+/// Long integer parts can be split into groups of three digits:
+/// ```rust
+/// # use repr_size::*;
+/// let bytes = SizeFormatter::new().exact_integers(true).thousands_separator(Some(','));
+///
+/// assert_eq!(bytes.format_as(Size::from(5_368_709_120u64), Units::Bytes), "5,368,709,120 B");
+/// assert_eq!(bytes.format_as(Size::from(999u64), Units::Bytes), "999 B");
+/// assert_eq!(
+///     bytes.thousands_separator(Some('_')).format_as(Size::MIB, Units::Bytes),
+///     "1_048_576 B",
+/// );
+/// # assert_eq!(bytes.format_as(Size::from(1000u64), Units::Bytes), "1,000 B");
+/// # assert_eq!(bytes.format_as(Size::from(100_000u64), Units::Bytes), "100,000 B");
+/// # assert_eq!(bytes.format_as(Size::MAX, Units::Bytes), "18,446,744,073,709,551,615 B");
+/// # assert_eq!(bytes.thousands_separator(Some('\u{2009}')).format_as(Size::from(12_345u64), Units::Bytes), "12\u{2009}345 B");
+/// # assert_eq!(bytes.precision(3).format_as(Size::from(1_234_567_891u64), Units::Kilobytes), "1,234,567.891 kB");
+/// # assert_eq!(bytes.format(Size::from(999_999u64)), "1.0 MB");
+/// # assert_eq!(SizeFormatter::new().format_as(Size::from(5_368_709_120u64), Units::Bytes), "5368709120 B");
+/// ```
+///
+/// The decimal mark is configurable as well, for locales that write "2,3 MB":
+/// ```rust
+/// # use repr_size::*;
+/// let german = SizeFormatter::new().thousands_separator(Some('.')).decimal_separator(',');
+///
+/// assert_eq!(german.format(Size::from(2_345_678u64)), "2,3 MB");
+/// assert_eq!(german.format_as(Size::from(1_234_500_000u64), Units::Megabytes), "1.234,5 MB");
+/// assert_eq!(SizeFormatter::new().format_as(Size::from(1_234_500_000u64), Units::Megabytes), "1234.5 MB");
+/// # assert_eq!(german.precision(2).format(Size::from(2_345_678u64)), "2,35 MB");
+/// # assert_eq!(SizeFormatter::new().precision(2).format(Size::from(2_345_678u64)), "2.35 MB");
+/// # assert_eq!(german.precision(0).format(Size::from(2_500_000u64)), "3 MB");
+/// # assert_eq!(german.trim_zeros(true).precision(3).format(Size::from(2_500_000u64)), "2,5 MB");
+/// ```
+///
+/// Formatters are `Copy`, so one can be built once, kept in a struct and reused
+/// for every row of a table. A default formatter writes exactly what
+/// `Size::to_decimal_string` does:
+/// ```rust
+/// # use repr_size::*;
+/// struct Table {
+///     sizes: SizeFormatter,
+/// }
+///
+/// let table = Table {
+///     sizes: SizeFormatter::new().base(Base::Binary).precision(2).trim_zeros(true).long_names(false),
+/// };
+/// let rows: Vec<String> = [1024u64, 1536, 1_500_000]
+///     .into_iter()
+///     .map(|bytes| table.sizes.format(Size::from(bytes)))
+///     .collect();
+///
+/// assert_eq!(rows, ["1 KiB", "1.5 KiB", "1.43 MiB"]);
+/// assert_eq!(SizeFormatter::new().format(Size::from(54_222u64)), Size::from(54_222u64).to_decimal_string());
+/// # let words = SizeFormatter::new().precision(0).long_names(true);
+/// # assert_eq!(words.format(Size::from(1_500_000u64)), "2 megabytes");
+/// # assert_eq!(words.long_names(false).format(Size::from(1_500_000u64)), "2 MB");
+/// # assert_eq!(SizeFormatter::new().base(Base::Binary).exact_integers(true).separator(Separator::None).format(Size::MIB), "1MiB");
+/// ```
+///
+/// With long names, a unit is singular only when the number shown is exactly one,
+/// so rounding decides it:
+/// ```rust
+/// # use repr_size::*;
+/// let words = SizeFormatter::new().unit_style(UnitStyle::Long);
+///
+/// assert_eq!(words.format(Size::from(1_040_000u64)), "1.0 megabyte");
+/// assert_eq!(words.format(Size::from(1_050_000u64)), "1.1 megabytes");
+/// assert_eq!(words.format(Size::from(1_500_000u64)), "1.5 megabytes");
+/// assert_eq!(words.format(Size::from(2_000_000u64)), "2.0 megabytes");
+/// # assert_eq!(words.format(Size::from(999_950u64)), "1.0 megabyte");
+/// # assert_eq!(words.format(Size::from(1u64)), "1 byte");
+/// # assert_eq!(words.format(Size::from(2u64)), "2 bytes");
+/// # assert_eq!(words.format(Size::from(1_049_999u64)), "1.0 megabyte");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SizeFormatter {
+    base: Base,
+    precision: usize,
+    /// Overrides `precision` for each unit of `Units::ALL` that has a value.
+    unit_precision: [Option<usize>; Units::ALL.len()],
+    significant_figures: Option<usize>,
+    min_unit: Option<Units>,
+    max_unit: Option<Units>,
+    /// How much of the next unit a size needs to be shown in it, in thousandths.
+    switch_threshold: u64,
+    zero_as_bytes: bool,
+    rounding: Rounding,
+    trim_zero_fraction: bool,
+    trim_zeros: bool,
+    exact_integers: bool,
+    separator: Separator,
+    /// The length every formatted size is padded to, with the numbers and units aligned.
+    tabular: Option<usize>,
+    thousands_separator: Option<char>,
+    decimal_separator: char,
+    unit_style: UnitStyle,
+    /// Replaces the symbol of each unit of `Units::ALL` that has a value.
+    symbol_overrides: [Option<&'static str>; Units::ALL.len()],
+    #[cfg(feature = "i18n")]
+    locale: Locale,
+    preset: Preset,
+}
+
+impl Default for SizeFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SizeFormatter {
+    /// Creates a formatter using decimal units and their symbols with one
+    /// decimal, like `Size::to_string`.
+    pub const fn new() -> Self {
+        Self {
+            base: Base::Decimal,
+            precision: 1,
+            unit_precision: [None; Units::ALL.len()],
+            significant_figures: None,
+            min_unit: None,
+            max_unit: None,
+            switch_threshold: 1000,
+            zero_as_bytes: true,
+            rounding: Rounding::HalfUp,
+            trim_zero_fraction: false,
+            trim_zeros: false,
+            exact_integers: false,
+            separator: Separator::Space,
+            tabular: None,
+            thousands_separator: None,
+            decimal_separator: '.',
+            unit_style: UnitStyle::Symbol,
+            symbol_overrides: [None; Units::ALL.len()],
+            #[cfg(feature = "i18n")]
+            locale: Locale::En,
+            preset: Preset::None,
+        }
+    }
+
+    /// Creates a formatter that spells out unit names and leaves out zero
+    /// fractions, like `Size::to_words`.
+    ///
+    /// A unit is singular only when the rounded number is exactly one, so it
+    /// follows the precision in use.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let words = SizeFormatter::words();
+    ///
+    /// assert_eq!(words.format(Size::ZERO), "0 bytes");
+    /// assert_eq!(words.format(Size::from(1u64)), "1 byte");
+    /// assert_eq!(words.format(Size::from(2u64)), "2 bytes");
+    /// assert_eq!(words.format(Size::from(1_500_000u64)), "1.5 megabytes");
+    /// assert_eq!(words.base(Base::Binary).format(Size::MIB), "1 mebibyte");
+    /// assert_eq!(words.precision(2).format(Size::from(1_040_000u64)), "1.04 megabytes");
+    /// assert_eq!(words.precision(0).format(Size::from(1_040_000u64)), "1 megabyte");
+    /// # assert_eq!(words.format(Size::from(1_040_000u64)), "1 megabyte");
+    /// # assert_eq!(words.format(Size::from(1_960_000u64)), "2 megabytes");
+    /// # assert_eq!(words.precision(0).format(Size::from(1_500_000u64)), "2 megabytes");
+    /// # assert_eq!(words.precision(0).format(Size::from(1_499_999u64)), "1 megabyte");
+    /// # assert_eq!(words.base(Base::Binary).format(Size::from(1_500_000u64)), "1.4 mebibytes");
+    /// ```
+    pub const fn words() -> Self {
+        Self::new()
+            .unit_style(UnitStyle::Long)
+            .trim_zero_fraction(true)
+    }
+
+    /// Creates a formatter that matches the sizes Windows Explorer shows.
+    ///
+    /// Units are binary but labelled "KB", "MB", "GB" and so on. Sizes under a
+    /// megabyte are shown in whole kilobytes rounded up, as in the Size column, so
+    /// even one byte is "1 KB". Larger sizes are cut down to three significant
+    /// figures, as in the details pane, moving to the next unit from 1000 of one.
+    /// Zero is "0 bytes".
+    ///
+    /// The precision, rounding, unit bounds and unit style are ignored. The sign,
+    /// padding and separators still apply.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let explorer = SizeFormatter::windows_explorer();
+    ///
+    /// assert_eq!(explorer.format(Size::from(1u64)), "1 KB");
+    /// assert_eq!(explorer.format(Size::from(2049u64)), "3 KB");
+    /// assert_eq!(explorer.format(Size::from(12_345_678u64)), "11.7 MB");
+    ///
+    /// let cases = [
+    ///     (0u64, "0 bytes"),
+    ///     (1, "1 KB"),
+    ///     (1023, "1 KB"),
+    ///     (1024, "1 KB"),
+    ///     (1025, "2 KB"),
+    ///     (2049, "3 KB"),
+    ///     (54_222, "53 KB"),
+    ///     (1_048_575, "1,024 KB"),
+    ///     (1_048_576, "1.00 MB"),
+    ///     (1_572_864, "1.50 MB"),
+    ///     (12_345_678, "11.7 MB"),
+    ///     (1_048_576_000, "0.97 GB"),
+    ///     (5_368_709_120, "5.00 GB"),
+    ///     (1_099_511_627_776, "1.00 TB"),
+    /// ];
+    /// for (bytes, shown) in cases {
+    ///     assert_eq!(explorer.format(Size::from(bytes)), shown);
+    /// }
+    /// # assert_eq!(explorer.format(Size::from(1_023_999u64)), "1,000 KB");
+    /// # assert_eq!(explorer.format(Size::from(1_048_575_999u64)), "999 MB");
+    /// # assert_eq!(explorer.format(Size::from(157_286_400u64)), "150 MB");
+    /// # assert_eq!(explorer.format(Size::MAX), "15.9 EB");
+    /// # assert_eq!(explorer.format_as(Size::from(22_000u64), Units::Bytes), "22,000 bytes");
+    /// # assert_eq!(explorer.format_as(Size::from(2049u64), Units::Kibibytes), "3 KB");
+    /// # assert_eq!(explorer.format_as(Size::from(22_000u64), Units::Kilobytes), "22 kB");
+    /// # assert_eq!(explorer.precision(3).rounding(Rounding::HalfUp).format(Size::from(2049u64)), "3 KB");
+    /// # assert_eq!(explorer.long_names(true).format(Size::from(2049u64)), "3 KB");
+    /// # assert_eq!(explorer.zero_as_bytes(false).format(Size::ZERO), "0 KB");
+    /// # assert_eq!(format!("[{:>8}]", explorer.display(Size::from(2049u64))), "[    3 KB]");
+    /// ```
+    pub const fn windows_explorer() -> Self {
+        Self {
+            preset: Preset::WindowsExplorer,
+            ..Self::new()
+                .base(Base::Binary)
+                .thousands_separator(Some(','))
+        }
+    }
+
+    /// Creates a formatter that writes sizes exactly like GNU `ls -lh` and `du -h`.
+    ///
+    /// Units are powers of 1024, written as a one-letter suffix with no space, and
+    /// sizes under 1024 bytes have no suffix at all. Numbers below 10 get one decimal
+    /// and larger ones none, always rounded up, so 1025 bytes is "1.1K". A size that
+    /// rounds up to 1024 of a unit moves to the next one, as "1.0M".
+    ///
+    /// This is built from `significant_figures(2)`, `Rounding::Ceil` and
+    /// `Separator::None`, which can be changed afterwards. The unit style is
+    /// ignored. `gnu_si` is the `--si` variant.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let gnu = SizeFormatter::gnu();
+    ///
+    /// assert_eq!(gnu.format(Size::from(54_222u64)), "53K");
+    /// assert_eq!(gnu.format(Size::from(1_181_116_006u64)), "1.1G");
+    /// assert_eq!(gnu.format(Size::from(1023u64)), "1023");
+    ///
+    /// // Taken from `ls -lh` in GNU coreutils 9.1.
+    /// let cases = [
+    ///     (0u64, "0"),
+    ///     (1, "1"),
+    ///     (1000, "1000"),
+    ///     (1023, "1023"),
+    ///     (1024, "1.0K"),
+    ///     (1025, "1.1K"),
+    ///     (1536, "1.5K"),
+    ///     (9900, "9.7K"),
+    ///     (10_137, "9.9K"),
+    ///     (10_240, "10K"),
+    ///     (10_241, "11K"),
+    ///     (54_222, "53K"),
+    ///     (102_400, "100K"),
+    ///     (999_999, "977K"),
+    ///     (1_022_976, "999K"),
+    ///     (1_022_977, "1000K"),
+    ///     (1_047_552, "1023K"),
+    ///     (1_048_000, "1.0M"),
+    ///     (1_048_575, "1.0M"),
+    ///     (1_048_576, "1.0M"),
+    ///     (1_048_577, "1.1M"),
+    ///     (10_485_760, "10M"),
+    ///     (10_590_617, "11M"),
+    ///     (1_073_741_824, "1.0G"),
+    ///     (1_181_116_006, "1.1G"),
+    ///     (5_368_709_120, "5.0G"),
+    ///     (1_099_511_627_776, "1.0T"),
+    /// ];
+    /// for (bytes, shown) in cases {
+    ///     assert_eq!(gnu.format(Size::from(bytes)), shown, "{} bytes", bytes);
+    /// }
+    /// # assert_eq!(gnu.format(Size::MAX), "16E");
+    /// # assert_eq!(gnu.long_names(true).format(Size::from(1025u64)), "1.1K");
+    /// # assert_eq!(gnu.format_as(Size::from(1025u64), Units::Bytes), "1025");
+    /// # assert_eq!(gnu.format_as(Size::from(12_000u64), Units::Kilobits), "96kbit");
+    /// # assert_eq!(format!("{:>5}", gnu.display(Size::from(54_222u64))), "  53K");
+    /// # assert_eq!(Size::from(54_222u64).to_gnu_string(), "53K");
+    /// ```
+    pub const fn gnu() -> Self {
+        Self {
+            preset: Preset::Gnu,
+            ..Self::new()
+                .base(Base::Binary)
+                .significant_figures(2)
+                .rounding(Rounding::Ceil)
+                .separator(Separator::None)
+        }
+    }
+
+    /// Creates a formatter that writes sizes exactly like GNU `ls -l --si` and
+    /// `du --si`, which is `gnu` with powers of 1000 and "k" for kilobytes.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let si = SizeFormatter::gnu_si();
+    ///
+    /// assert_eq!(si.format(Size::from(54_222u64)), "55k");
+    /// assert_eq!(si.format(Size::from(1_181_116_006u64)), "1.2G");
+    ///
+    /// // Taken from `ls -l --si` in GNU coreutils 9.1.
+    /// let cases = [
+    ///     (0u64, "0"),
+    ///     (999, "999"),
+    ///     (1000, "1.0k"),
+    ///     (1001, "1.1k"),
+    ///     (1024, "1.1k"),
+    ///     (1536, "1.6k"),
+    ///     (9900, "9.9k"),
+    ///     (9901, "10k"),
+    ///     (10_240, "11k"),
+    ///     (54_222, "55k"),
+    ///     (102_400, "103k"),
+    ///     (999_000, "999k"),
+    ///     (999_001, "1.0M"),
+    ///     (1_000_000, "1.0M"),
+    ///     (1_048_576, "1.1M"),
+    ///     (10_485_760, "11M"),
+    ///     (1_073_741_824, "1.1G"),
+    ///     (5_368_709_120, "5.4G"),
+    ///     (1_099_511_627_776, "1.1T"),
+    /// ];
+    /// for (bytes, shown) in cases {
+    ///     assert_eq!(si.format(Size::from(bytes)), shown, "{} bytes", bytes);
+    /// }
+    /// # assert_eq!(si.format(Size::MAX), "19E");
+    /// ```
+    pub const fn gnu_si() -> Self {
+        Self::gnu().base(Base::Decimal)
+    }
+
+    /// Sets the family of units to pick from.
+    pub const fn base(self, base: Base) -> Self {
+        Self { base, ..self }
+    }
+
+    /// Sets the number of decimals shown. With zero, there is no decimal point.
+    /// Sizes in bytes are always whole, so they never have decimals.
+    pub const fn precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+
+    /// Sets the number of decimals shown for every unit in `units`, in place of
+    /// the precision. Units are compared by size, so a range also covers the
+    /// units of the other base and bits that fall inside it.
+    ///
+    /// This applies to whichever unit is picked, so small sizes can be whole and
+    /// large ones precise, as Finder shows them.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let finder = SizeFormatter::new()
+    ///     .precision(2)
+    ///     .precision_for(..Units::Megabytes, 0)
+    ///     .precision_for(Units::Megabytes..Units::Gigabytes, 1);
+    ///
+    /// assert_eq!(finder.format(Size::from(999u64)), "999 B");
+    /// assert_eq!(finder.format(Size::from(54_222u64)), "54 kB");
+    /// assert_eq!(finder.format(Size::from(2_345_678u64)), "2.3 MB");
+    /// assert_eq!(finder.format(Size::from(5_368_709_120u64)), "5.37 GB");
+    /// assert_eq!(finder.format(Size::TB), "1.00 TB");
+    /// # assert_eq!(finder.format(Size::from(999_999u64)), "1.0 MB");
+    /// # assert_eq!(finder.format_as(Size::from(2_345_678u64), Units::Kilobytes), "2346 kB");
+    /// # // A megabit is smaller than a megabyte.
+    /// # assert_eq!(finder.format_as(Size::from(12_500_000u64), Units::Megabits), "100 Mbit");
+    /// # assert_eq!(finder.format_as(Size::from(125_000_000_000u64), Units::Gigabits), "1000.0 Gbit");
+    /// # assert_eq!(finder.format(Size::MAX), "18.45 EB");
+    /// # assert_eq!(finder.base(Base::Binary).format(Size::from(54_222u64)), "53 KiB");
+    /// # assert_eq!(finder.base(Base::Binary).format(Size::from(2_345_678u64)), "2.2 MiB");
+    /// # assert_eq!(finder.base(Base::Binary).format(Size::from(5_368_709_120u64)), "5.00 GiB");
+    /// # assert_eq!(finder.precision_for(.., 3).format(Size::from(54_222u64)), "54.222 kB");
+    /// # assert_eq!(finder.precision_for(Units::Kilobytes..=Units::Kilobytes, 1).format(Size::from(54_222u64)), "54.2 kB");
+    /// # assert_eq!(finder.precision(4).format(Size::TB), "1.0000 TB");
+    /// # assert_eq!(finder.precision(4).format(Size::from(54_222u64)), "54 kB");
+    /// # assert_eq!(finder.significant_figures(3).format(Size::from(54_222u64)), "54.2 kB");
+    /// ```
+    pub fn precision_for(self, units: impl RangeBounds<Units>, decimals: usize) -> Self {
+        let mut unit_precision = self.unit_precision;
+        for (i, unit) in Units::ALL.iter().enumerate() {
+            if units.contains(unit) {
+                unit_precision[i] = Some(decimals);
+            }
+        }
+        Self {
+            unit_precision,
+            ..self
+        }
+    }
+
+    /// Shows `figures` significant figures rather than a fixed number of decimals,
+    /// like coreutils does, so that at three figures sizes print as "1.02 GB",
+    /// "54.2 kB" and "999 kB". This takes the place of the precision.
+    ///
+    /// Decimals are only ever dropped, so sizes of 1000 or more of a unit keep
+    /// every digit of their integer part. When rounding reaches the next unit, the
+    /// size is shown in that unit instead, as "1.00 GB" rather than "1000 MB".
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let coreutils = SizeFormatter::new().significant_figures(3);
+    ///
+    /// assert_eq!(coreutils.format(Size::from(1_024_000_000u64)), "1.02 GB");
+    /// assert_eq!(coreutils.format(Size::from(54_222u64)), "54.2 kB");
+    /// assert_eq!(coreutils.format(Size::from(999_000u64)), "999 kB");
+    /// assert_eq!(coreutils.format(Size::from(999_600_000u64)), "1.00 GB");
+    /// # assert_eq!(coreutils.format(Size::from(9_994_000u64)), "9.99 MB");
+    /// # assert_eq!(coreutils.format(Size::from(9_995_000u64)), "10.0 MB");
+    /// # assert_eq!(coreutils.format(Size::from(99_940_000u64)), "99.9 MB");
+    /// # assert_eq!(coreutils.format(Size::from(99_950_000u64)), "100 MB");
+    /// # assert_eq!(coreutils.format(Size::from(999_499_999u64)), "999 MB");
+    /// # assert_eq!(coreutils.format(Size::from(999_500_000u64)), "1.00 GB");
+    /// # assert_eq!(coreutils.format(Size::from(1_000_000u64)), "1.00 MB");
+    /// # assert_eq!(coreutils.format(Size::from(5u64)), "5 B");
+    /// # assert_eq!(coreutils.format(Size::MAX), "18.4 EB");
+    /// # assert_eq!(coreutils.format(Size::ZERO), "0 B");
+    /// # assert_eq!(coreutils.format_as(Size::from(999_600_000u64), Units::Megabytes), "1000 MB");
+    /// # assert_eq!(coreutils.format_as(Size::from(1_234_567u64), Units::Kilobytes), "1235 kB");
+    /// # assert_eq!(coreutils.significant_figures(1).format(Size::from(1_500_000u64)), "2 MB");
+    /// # assert_eq!(coreutils.significant_figures(5).format(Size::from(1_234_567u64)), "1.2346 MB");
+    /// # assert_eq!(coreutils.rounding(Rounding::Floor).format(Size::from(999_999_999u64)), "999 MB");
+    /// # assert_eq!(coreutils.trim_zeros(true).format(Size::from(999_600_000u64)), "1 GB");
+    /// let binary = coreutils.base(Base::Binary);
+    /// assert_eq!(binary.format(Size::from(1_500_000u64)), "1.43 MiB");
+    /// assert_eq!(binary.format(Size::from(1_048_000u64)), "1023 KiB");
+    /// assert_eq!(binary.format(Size::from(1_048_500u64)), "1.00 MiB");
+    /// # assert_eq!(binary.format(Size::from(1_000_000u64)), "977 KiB");
+    /// # assert_eq!(binary.format(Size::from(1000u64)), "1000 B");
+    /// # assert_eq!(binary.format(Size::from(1023u64)), "1023 B");
+    /// # assert_eq!(binary.format(Size::MAX), "16.0 EiB");
+    /// ```
+    pub const fn significant_figures(self, figures: usize) -> Self {
+        Self {
+            significant_figures: Some(figures),
+            ..self
+        }
+    }
+
+    /// Sets the smallest unit to pick automatically, so that sizes below it are
+    /// shown as a fraction of it. Zero is only shown in this unit without
+    /// `zero_as_bytes`.
+    ///
+    /// # Panics
+    /// In debug builds, if `unit` is larger than the `max_unit`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let no_bytes = SizeFormatter::new().min_unit(Units::Kilobytes);
+    ///
+    /// assert_eq!(no_bytes.format(Size::from(12u64)), "0.0 kB");
+    /// assert_eq!(no_bytes.precision(3).format(Size::from(12u64)), "0.012 kB");
+    /// assert_eq!(no_bytes.format(Size::from(3_000_000u64)), "3.0 MB");
+    /// # assert_eq!(no_bytes.format(Size::ZERO), "0 B");
+    /// # assert_eq!(no_bytes.zero_as_bytes(false).format(Size::ZERO), "0.0 kB");
+    /// # assert_eq!(no_bytes.format(Size::from(54_222u64)), "54.2 kB");
+    /// # assert_eq!(no_bytes.format_as(Size::from(12u64), Units::Bytes), "12 B");
+    /// # assert_eq!(no_bytes.base(Base::Binary).format(Size::from(512u64)), "0.5 kB");
+    /// # assert_eq!(no_bytes.base(Base::Binary).format(Size::from(2048u64)), "2.0 KiB");
+    /// ```
+    pub const fn min_unit(self, unit: Units) -> Self {
+        check_unit_bounds(Some(unit), self.max_unit);
+        Self {
+            min_unit: Some(unit),
+            ..self
+        }
+    }
+
+    /// Sets the largest unit to pick automatically, so that sizes above it are
+    /// shown as many of it.
+    ///
+    /// # Panics
+    /// In debug builds, if `unit` is smaller than the `min_unit`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let megabytes = SizeFormatter::new().max_unit(Units::Megabytes);
+    ///
+    /// assert_eq!(megabytes.format(Size::from_units(3, Units::Terabytes)), "3000000.0 MB");
+    /// assert_eq!(megabytes.format(Size::from(54_222u64)), "54.2 kB");
+    /// assert_eq!(megabytes.min_unit(Units::Megabytes).format(Size::from(54_222u64)), "0.1 MB");
+    /// # assert_eq!(megabytes.format(Size::from(999_960_000u64)), "1000.0 MB");
+    /// # assert_eq!(megabytes.format(Size::from(999_960u64)), "1.0 MB");
+    /// # assert_eq!(megabytes.format(Size::from(999u64)), "999 B");
+    /// # assert_eq!(megabytes.format(Size::ZERO), "0 B");
+    /// # assert_eq!(megabytes.min_unit(Units::Megabytes).format(Size::ZERO), "0 B");
+    /// # assert_eq!(megabytes.min_unit(Units::Megabytes).zero_as_bytes(false).format(Size::ZERO), "0.0 MB");
+    /// # assert_eq!(megabytes.base(Base::Binary).format(Size::GIB), "1073.7 MB");
+    /// # assert_eq!(megabytes.max_unit(Units::Bytes).format(Size::MAX), "18446744073709551615 B");
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use repr_size::*;
+    /// # if !cfg!(debug_assertions) { panic!() }
+    /// let _ = SizeFormatter::new().min_unit(Units::Gigabytes).max_unit(Units::Megabytes);
+    /// ```
+    pub const fn max_unit(self, unit: Units) -> Self {
+        check_unit_bounds(self.min_unit, Some(unit));
+        Self {
+            max_unit: Some(unit),
+            ..self
+        }
+    }
+
+    /// Sets how much of a unit a size needs before it is picked automatically, as a
+    /// multiple of that unit. At the default of 1.0, 1000 bytes is "1.0 kB"; at 1.5,
+    /// sizes stay in bytes up to 1499 and in kilobytes up to "1499.9 kB". This
+    /// applies to both bases and to every unit.
+    ///
+    /// # Panics
+    /// If `threshold` is less than 1.0 or NaN.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let late = SizeFormatter::new().switch_threshold(1.5);
+    ///
+    /// assert_eq!(late.format(Size::from(1_400_000u64)), "1400.0 kB");
+    /// assert_eq!(late.format(Size::from(1_500_000u64)), "1.5 MB");
+    /// assert_eq!(late.base(Base::Binary).format(Size::from(1_400_000u64)), "1367.2 KiB");
+    /// # assert_eq!(late.format(Size::from(1499u64)), "1499 B");
+    /// # assert_eq!(late.format(Size::from(1500u64)), "1.5 kB");
+    /// # assert_eq!(late.format(Size::from(1_499_940u64)), "1499.9 kB");
+    /// # assert_eq!(late.format(Size::from(1_499_950u64)), "1.5 MB");
+    /// # assert_eq!(late.format(Size::from(999_999u64)), "1000.0 kB");
+    /// # assert_eq!(late.base(Base::Binary).format(Size::from(1_572_800u64)), "1535.9 KiB");
+    /// # assert_eq!(late.base(Base::Binary).format(Size::from(1_572_863u64)), "1.5 MiB");
+    /// # assert_eq!(late.base(Base::Binary).format(Size::from(1_572_864u64)), "1.5 MiB");
+    /// # assert_eq!(late.base(Base::Binary).format(Size::from(1535u64)), "1535 B");
+    /// # assert_eq!(late.base(Base::Binary).format(Size::from(1536u64)), "1.5 KiB");
+    /// # let default = SizeFormatter::new().switch_threshold(1.0);
+    /// # for bytes in [0u64, 1, 999, 1000, 999_949, 999_950, 1_000_000, 1_048_575, 1_048_576, u64::MAX] {
+    /// #     assert_eq!(default.format(Size::from(bytes)), Size::from(bytes).to_decimal_string());
+    /// #     assert_eq!(default.base(Base::Binary).format(Size::from(bytes)), Size::from(bytes).to_binary_string());
+    /// # }
+    /// # let double = SizeFormatter::new().switch_threshold(2.0);
+    /// # assert_eq!(double.format(Size::from(1_999_999u64)), "2.0 MB");
+    /// # assert_eq!(double.format(Size::from(1_999_900u64)), "1999.9 kB");
+    /// # assert_eq!(double.format(Size::from(2_000_000u64)), "2.0 MB");
+    /// # assert_eq!(double.precision(0).format(Size::from(1_999_499u64)), "1999 kB");
+    /// # assert_eq!(SizeFormatter::new().switch_threshold(1.001).format(Size::from(1_000_900u64)), "1000.9 kB");
+    /// # assert_eq!(SizeFormatter::new().switch_threshold(1.001).format(Size::from(1_001_000u64)), "1.0 MB");
+    /// # assert_eq!(late.max_unit(Units::Kilobytes).format(Size::from(1_500_000u64)), "1500.0 kB");
+    /// # assert_eq!(late.format(Size::MAX), "18.4 EB");
+    /// # assert_eq!(SizeFormatter::new().switch_threshold(1e30).format(Size::from(5_000_000u64)), "5000000 B");
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use repr_size::*;
+    /// let _ = SizeFormatter::new().switch_threshold(0.9);
+    /// ```
+    #[cfg(feature = "float")]
+    pub const fn switch_threshold(self, threshold: f64) -> Self {
+        assert!(threshold >= 1.0, "switch_threshold is less than 1.0");
+        Self {
+            // Rounds to the nearest thousandth, and saturates for thresholds too
+            // large to ever reach.
+            switch_threshold: (threshold * 1000.0 + 0.5) as u64,
+            ..self
+        }
+    }
+
+    /// Sets whether zero is always written as "0 B", even when formatting in a
+    /// given unit or with a `min_unit`. This is on by default; turn it off for
+    /// tables where every row must share a unit.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let formatter = SizeFormatter::new();
+    ///
+    /// assert_eq!(formatter.format_as(Size::ZERO, Units::Mebibytes), "0 B");
+    /// assert_eq!(formatter.zero_as_bytes(false).format_as(Size::ZERO, Units::Mebibytes), "0.0 MiB");
+    /// assert_eq!(formatter.zero_as_bytes(false).format(Size::ZERO), "0 B");
+    /// # assert_eq!(formatter.format(Size::ZERO), "0 B");
+    /// # assert_eq!(formatter.precision(3).format_as(Size::ZERO, Units::Gigabytes), "0 B");
+    /// # assert_eq!(formatter.zero_as_bytes(false).precision(0).format_as(Size::ZERO, Units::Gigabytes), "0 GB");
+    /// # assert_eq!(formatter.long_names(true).format_as(Size::ZERO, Units::Kibibytes), "0 bytes");
+    /// # assert_eq!(formatter.separator(Separator::None).format_as(Size::ZERO, Units::Megabytes), "0B");
+    /// # assert_eq!(format!("[{:>6}]", formatter.display_as(Size::ZERO, Units::Megabytes)), "[   0 B]");
+    /// # assert_eq!(format!("[{:<+6}]", formatter.display_as(Size::ZERO, Units::Megabytes)), "[+0 B  ]");
+    /// # assert_eq!(format!("[{:>+8}]", formatter.zero_as_bytes(false).display_as(Size::ZERO, Units::Megabytes)), "[ +0.0 MB]");
+    /// # assert_eq!(formatter.significant_figures(3).format_as(Size::ZERO, Units::Megabytes), "0 B");
+    /// # assert_eq!(formatter.exact_integers(true).zero_as_bytes(false).format_as(Size::ZERO, Units::Mebibytes), "0 MiB");
+    /// ```
+    pub const fn zero_as_bytes(self, zero_as_bytes: bool) -> Self {
+        Self {
+            zero_as_bytes,
+            ..self
+        }
+    }
+
+    /// Sets how the number is rounded to the precision.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let size = Size::from(1_999_000u64);
+    ///
+    /// assert_eq!(SizeFormatter::new().format(size), "2.0 MB");
+    /// assert_eq!(SizeFormatter::new().rounding(Rounding::Floor).format(size), "1.9 MB");
+    /// # assert_eq!(SizeFormatter::new().rounding(Rounding::Ceil).format(Size::from(1_901_000u64)), "2.0 MB");
+    /// # assert_eq!(SizeFormatter::words().rounding(Rounding::Floor).format(Size::from(1_099_999u64)), "1 megabyte");
+    /// # assert_eq!(SizeFormatter::words().rounding(Rounding::Ceil).format(Size::from(1_000_001u64)), "1.1 megabytes");
+    /// ```
+    pub const fn rounding(self, rounding: Rounding) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Sets whether to leave out the fraction when every shown decimal is zero,
+    /// so "2.0 MB" prints as "2 MB" but "2.50 MB" stays as it is.
+    pub const fn trim_zero_fraction(self, trim_zero_fraction: bool) -> Self {
+        Self {
+            trim_zero_fraction,
+            ..self
+        }
+    }
+
+    /// Sets whether to leave out every zero at the end of the fraction, so "2.50 MB"
+    /// prints as "2.5 MB" and "2.00 MB" as "2 MB".
+    pub const fn trim_zeros(self, trim_zeros: bool) -> Self {
+        Self { trim_zeros, ..self }
+    }
+
+    /// Sets whether to leave out the fraction when the size is an exact multiple
+    /// of its unit.
+    pub const fn exact_integers(self, exact_integers: bool) -> Self {
+        Self {
+            exact_integers,
+            ..self
+        }
+    }
+
+    /// Sets what goes between the number and the unit.
+    pub const fn separator(self, separator: Separator) -> Self {
+        Self { separator, ..self }
+    }
+
+    /// Sets the character that splits the integer part of the number into groups
+    /// of three digits, such as `Some(',')` for "5,368,709,120 B", or `None` to
+    /// leave it unsplit. The decimals and the unit are never split.
+    pub const fn thousands_separator(self, thousands_separator: Option<char>) -> Self {
+        Self {
+            thousands_separator,
+            ..self
+        }
+    }
+
+    /// Sets the character written between the integer part and the decimals,
+    /// which is `'.'` by default.
+    pub const fn decimal_separator(self, decimal_separator: char) -> Self {
+        Self {
+            decimal_separator,
+            ..self
+        }
+    }
+
+    /// Sets how the unit is named.
+    pub const fn unit_style(self, unit_style: UnitStyle) -> Self {
+        Self { unit_style, ..self }
+    }
+
+    /// Sets the symbol written for each unit in `overrides`, in place of its usual
+    /// one, such as "Mo" for megabytes. Units that aren't overridden keep their
+    /// symbol, and overrides given earlier stay unless replaced.
+    ///
+    /// Only symbols are replaced, so long names are unaffected.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let octets = SizeFormatter::new().symbol_overrides([
+    ///     (Units::Bytes, "o"),
+    ///     (Units::Kilobytes, "ko"),
+    ///     (Units::Megabytes, "Mo"),
+    ///     (Units::Gigabytes, "Go"),
+    /// ]);
+    ///
+    /// assert_eq!(octets.format(Size::from(999u64)), "999 o");
+    /// assert_eq!(octets.format(Size::from(54_222u64)), "54.2 ko");
+    /// assert_eq!(octets.format(Size::from(2_345_678u64)), "2.3 Mo");
+    /// assert_eq!(octets.format(Size::TB), "1.0 TB");
+    ///
+    /// let capital_k = SizeFormatter::new().symbol_overrides([(Units::Kilobytes, "KB")]);
+    /// assert_eq!(capital_k.format(Size::from(54_222u64)), "54.2 KB");
+    /// assert_eq!(capital_k.format(Size::from(2_345_678u64)), "2.3 MB");
+    /// # assert_eq!(octets.format(Size::ZERO), "0 o");
+    /// # assert_eq!(octets.format_as(Size::from(5_368_709_120u64), Units::Gigabytes), "5.4 Go");
+    /// # assert_eq!(octets.precision(3).format(Size::from(54_222u64)), "54.222 ko");
+    /// # assert_eq!(octets.separator(Separator::None).format(Size::from(54_222u64)), "54.2ko");
+    /// # assert_eq!(octets.decimal_separator(',').thousands_separator(Some(' ')).format_as(Size::from(1_234_567u64), Units::Bytes), "1 234 567 o");
+    /// # assert_eq!(octets.long_names(true).format(Size::from(54_222u64)), "54.2 kilobytes");
+    /// # assert_eq!(octets.long_names(true).long_names(false).format(Size::from(54_222u64)), "54.2 ko");
+    /// # assert_eq!(octets.symbol_overrides([(Units::Kilobytes, "kio")]).format(Size::from(54_222u64)), "54.2 kio");
+    /// # assert_eq!(octets.symbol_overrides([(Units::Kilobytes, "kio")]).format(Size::from(999u64)), "999 o");
+    /// # assert_eq!(format!("[{:>+9}]", octets.display(Size::from(54_222u64))), "[ +54.2 ko]");
+    /// # assert_eq!(octets.base(Base::Binary).format(Size::from(54_222u64)), "53.0 KiB");
+    /// # let map: std::collections::HashMap<Units, &'static str> = [(Units::Kibibytes, "Kio")].into_iter().collect();
+    /// # assert_eq!(SizeFormatter::new().base(Base::Binary).symbol_overrides(map).format(Size::from(54_222u64)), "53.0 Kio");
+    /// # assert_eq!(SizeFormatter::gnu().symbol_overrides([(Units::Kibibytes, "k")]).format(Size::from(54_222u64)), "53k");
+    /// ```
+    pub fn symbol_overrides(self, overrides: impl IntoIterator<Item = (Units, &'static str)>) -> Self {
+        let mut symbol_overrides = self.symbol_overrides;
+        for (unit, symbol) in overrides {
+            symbol_overrides[index_in(Units::ALL, unit)] = Some(symbol);
+        }
+        Self {
+            symbol_overrides,
+            ..self
+        }
+    }
+
+    /// Sets the language unit symbols and names are written in.
+    #[cfg(feature = "i18n")]
+    pub const fn locale(self, locale: Locale) -> Self {
+        Self { locale, ..self }
+    }
+
+    /// Sets whether the unit is named in full, as with `UnitStyle::Long`, or by
+    /// its symbol, as with `UnitStyle::Symbol`.
+    pub const fn long_names(self, long_names: bool) -> Self {
+        let unit_style = if long_names {
+            UnitStyle::Long
+        } else {
+            UnitStyle::Symbol
+        };
+        Self { unit_style, ..self }
+    }
+
+    /// Pads every formatted size to `width` characters for a table column, with
+    /// the decimal points and the units lined up.
+    ///
+    /// The integer part is right-aligned, and the fraction is padded to the most
+    /// decimals any unit is shown with, so sizes in bytes leave a gap where the
+    /// decimals would be. The unit is left-aligned and padded to the longest name
+    /// in the base, as "B " against "kB". The length is constant as long as the
+    /// integer part fits, which with automatic units and the default precision
+    /// takes a `width` of 8 for decimal units and 10 for binary ones.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let column = SizeFormatter::new().tabular(8);
+    ///
+    /// assert_eq!(column.format(Size::from(4_200_000u64)), "  4.2 MB");
+    /// assert_eq!(column.format(Size::from(512_000u64)), "512.0 kB");
+    /// assert_eq!(column.format(Size::from(999u64)), "999   B ");
+    ///
+    /// let sizes = [0u64, 7, 999, 1000, 54_222, 999_949, 4_200_000, 1_500_000_000, u64::MAX];
+    /// let rows: Vec<String> = sizes.iter().map(|&bytes| column.format(Size::from(bytes))).collect();
+    /// for row in &rows {
+    ///     assert_eq!(row.len(), 8);
+    ///     assert!(row.find('.').is_none_or(|point| point == 3), "{:?}", row);
+    /// }
+    /// # assert_eq!(rows[0], "  0   B ");
+    /// # assert_eq!(rows[8], " 18.4 EB");
+    /// # let binary = SizeFormatter::new().base(Base::Binary).tabular(10);
+    /// # for bytes in [0u64, 1023, 1_048_524, 5_368_709_120, u64::MAX] {
+    /// #     let row = binary.format(Size::from(bytes));
+    /// #     assert_eq!(row.len(), 10, "{:?}", row);
+    /// #     assert!(row.find('.').is_none_or(|point| point == 4), "{:?}", row);
+    /// # }
+    /// # assert_eq!(binary.format(Size::from(1023u64)), "1023   B  ");
+    /// # assert_eq!(binary.format(Size::from(1_048_524u64)), "1023.9 KiB");
+    /// # let wide = SizeFormatter::new().precision(2).trim_zeros(true).tabular(10);
+    /// # assert_eq!(wide.format(Size::from(2_500_000u64)), "   2.5  MB");
+    /// # assert_eq!(wide.format(Size::from(2_000_000u64)), "   2    MB");
+    /// # assert_eq!(wide.format(Size::from(2_345_678u64)), "   2.35 MB");
+    /// # assert_eq!(format!("{:+}", column.display(Size::from(4_200_000u64))), " +4.2 MB");
+    /// # assert_eq!(format!("[{:>10}]", column.display(Size::from(4_200_000u64))), "[    4.2 MB]");
+    /// # assert_eq!(column.format(Size::from(123_456_789_000u64)), "123.5 GB");
+    /// # assert_eq!(column.format_as(Size::from(1_234_567u64), Units::Kilobytes), "1234.6 kB");
+    /// # assert_eq!(column.format_as(Size::from(12_000u64), Units::Kilobits), "96.0 kbit");
+    /// # assert_eq!(column.long_names(true).format(Size::from(4_200_000u64)), "4.2 megabytes");
+    /// # assert_eq!(column.long_names(true).tabular(14).format(Size::from(1u64)), " 1   byte     ");
+    /// # assert_eq!(column.precision(0).format(Size::from(4_200_000u64)), "    4 MB");
+    /// # assert_eq!(SizeFormatter::new().tabular(0).format(Size::from(999u64)), "999   B ");
+    /// ```
+    pub const fn tabular(self, width: usize) -> Self {
+        Self {
+            tabular: Some(width),
+            ..self
+        }
+    }
+
+    /// Formats the size into a new `String`, picking the unit automatically.
+    ///
+    /// The unit is picked before rounding, so if rounding reaches the next unit,
+    /// the size is shown in that one instead.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let formatter = SizeFormatter::new();
+    ///
+    /// assert_eq!(formatter.format(Size::from(999_960u64)), "1.0 MB");
+    /// assert_eq!(formatter.base(Base::Binary).format(Size::from(1_048_570u64)), "1.0 MiB");
+    /// assert_eq!(formatter.format_as(Size::from(999_960u64), Units::Kilobytes), "1000.0 kB");
+    /// # assert_eq!(formatter.format(Size::from(999_949u64)), "999.9 kB");
+    /// # assert_eq!(formatter.format(Size::from(999_950u64)), "1.0 MB");
+    /// # assert_eq!(formatter.precision(0).format(Size::from(999_499u64)), "999 kB");
+    /// # assert_eq!(formatter.precision(0).format(Size::from(999_500u64)), "1 MB");
+    /// # assert_eq!(formatter.precision(2).format(Size::from(999_994u64)), "999.99 kB");
+    /// # assert_eq!(formatter.precision(2).format(Size::from(999_995u64)), "1.00 MB");
+    /// # assert_eq!(formatter.precision(3).format(Size::from(999_999_999u64)), "1.000 GB");
+    /// # assert_eq!(formatter.base(Base::Binary).format(Size::from(1_048_524u64)), "1023.9 KiB");
+    /// # assert_eq!(formatter.base(Base::Binary).precision(0).format(Size::from(1_048_064u64)), "1 MiB");
+    /// # assert_eq!(formatter.base(Base::Binary).precision(0).format(Size::from(1_048_063u64)), "1023 KiB");
+    /// # assert_eq!(formatter.base(Base::Binary).format(Size::from(1023u64)), "1023 B");
+    /// # assert_eq!(formatter.rounding(Rounding::Floor).format(Size::from(999_999u64)), "999.9 kB");
+    /// # assert_eq!(formatter.rounding(Rounding::Ceil).format(Size::from(999_901u64)), "1.0 MB");
+    /// # assert_eq!(formatter.format(Size::MAX), "18.4 EB");
+    /// # assert_eq!(Size::from(999_960u64).to_decimal_string(), "1.0 MB");
+    /// # assert_eq!(Size::from(1_048_570u64).to_binary_string(), "1.0 MiB");
+    /// # if !cfg!(feature = "default-binary") {
+    /// #     assert_eq!(format!("{:.0}", Size::from(999_500u64)), "1 MB");
+    /// # }
+    /// # assert_eq!(Size::from(999_960u64).repr(Units::Kilobytes), "1000.0 kB");
+    /// # assert_eq!(Size128::from(999_960u64).to_decimal_string(), "1.0 MB");
+    /// # assert_eq!(Size128::from(1_048_570u64).to_binary_string(), "1.0 MiB");
+    /// ```
+    pub fn format(&self, size: Size) -> String {
+        self.display(size).to_string()
+    }
+
+    /// Formats the size into a new `String` using the given unit, like `Size::repr`.
+    pub fn format_as(&self, size: Size, unit: Units) -> String {
+        self.display_as(size, unit).to_string()
+    }
+
+    /// Returns an adapter that writes the size like `format` does, without
+    /// allocating, for use in `format_args!`, `write!` or logging macros.
+    ///
+    /// The adapter pads to a width like a `str`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// use std::fmt::Write;
+    ///
+    /// let formatter = SizeFormatter::new().base(Base::Binary).precision(2);
+    /// let mut line = String::new();
+    /// write!(line, "[{:>10}]", formatter.display(Size::from(1_500_000u64))).unwrap();
+    ///
+    /// assert_eq!(line, "[  1.43 MiB]");
+    /// # assert_eq!(format!("{:<8}|", SizeFormatter::new().display(Size::ZERO)), "0 B     |");
+    /// # assert_eq!(format!("{:^9}", SizeFormatter::new().display(Size::KB)), " 1.0 kB  ");
+    /// ```
+    pub fn display(&self, size: Size) -> impl Display + '_ {
+        FormattedSize {
+            formatter: self,
+            size,
+            unit: None,
+        }
+    }
+
+    /// Returns an adapter that writes the size like `format_as` does, without allocating.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let formatter = SizeFormatter::new().long_names(true);
+    ///
+    /// assert_eq!(format!("{}", formatter.display_as(Size::MIB, Units::Kibibytes)), "1024.0 kibibytes");
+    /// # assert_eq!(format!("{}", formatter.display_as(Size::ZERO, Units::Kibibytes)), "0 bytes");
+    /// ```
+    pub fn display_as(&self, size: Size, unit: Units) -> impl Display + '_ {
+        FormattedSize {
+            formatter: self,
+            size,
+            unit: Some(unit),
+        }
+    }
+
+    /// Formats every size in the unit `format` would pick for the largest of them,
+    /// padded to the same length as with `tabular`, so they line up in a column.
+    ///
+    /// The length is that of the largest size, unless `tabular` gave one.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let sizes = [Size::from(4_200_000u64), Size::from(512_000u64), Size::from(999u64)];
+    /// let rows: Vec<String> = SizeFormatter::new().format_column(&sizes).collect();
+    ///
+    /// assert_eq!(rows, ["4.2 MB", "0.5 MB", "0.0 MB"]);
+    ///
+    /// let sizes = [Size::from(12_345_678u64), Size::from(2_000_000u64), Size::ZERO];
+    /// let rows: Vec<String> = SizeFormatter::new().precision(2).format_column(&sizes).collect();
+    /// assert_eq!(rows, ["12.35 MB", " 2.00 MB", " 0    B "]);
+    /// # let rows: Vec<String> = SizeFormatter::new().tabular(9).format_column(&sizes).collect();
+    /// # assert_eq!(rows, ["  12.3 MB", "   2.0 MB", "   0   B "]);
+    /// # assert_eq!(SizeFormatter::new().format_column(&[]).count(), 0);
+    /// # let rows: Vec<String> = SizeFormatter::new().base(Base::Binary).format_column(&[Size::MIB, Size::KIB, Size::from(1u64)]).collect();
+    /// # assert_eq!(rows, ["1.0 MiB", "0.0 MiB", "0.0 MiB"]);
+    /// # let rows: Vec<String> = SizeFormatter::new().format_column(&[Size::from(999_960u64), Size::from(1000u64)]).collect();
+    /// # assert_eq!(rows, ["1.0 MB", "0.0 MB"]);
+    /// ```
+    pub fn format_column<'a>(&self, sizes: &'a [Size]) -> impl Iterator<Item = String> + 'a {
+        let largest = sizes.iter().copied().max().unwrap_or(Size::ZERO);
+        let (unit, _) = self.amount(largest, self.auto_unit(largest), true);
+        let width = match self.tabular {
+            Some(width) => width,
+            None => self.tabular(0).format_as(largest, unit).chars().count(),
+        };
+        let column = self.tabular(width);
+        sizes.iter().map(move |size| column.format_as(*size, unit))
+    }
+
+    /// Writes the size in the given unit, or in the unit `format` would pick,
+    /// with a leading "+" for the `+` flag.
+    fn write(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        size: Size,
+        unit: Option<Units>,
+    ) -> std::fmt::Result {
+        let sign = if f.sign_plus() { "+" } else { "" };
+        if size.is_zero() && self.zero_as_bytes {
+            return self.write_parts(f, sign, 0, self.unit_name(Units::Bytes, false));
+        }
+        let picked = unit.is_none();
+        let unit = unit.unwrap_or_else(|| self.auto_unit(size));
+        let (unit, mut amount) = self.amount(size, unit, picked);
+        if self.exact_integers {
+            amount = amount.exact_integer();
+        }
+        if self.trim_zeros {
+            amount = amount.trim_zeros();
+        } else if self.trim_zero_fraction {
+            amount = amount.trim_zero_fraction();
+        }
+        amount = amount.separators(self.thousands_separator, self.decimal_separator);
+        self.write_parts(f, sign, amount, self.unit_name(unit, amount.is_one()))
+    }
+
+    /// Writes the sign, number and unit, lined up as set by `tabular`.
+    fn write_parts(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        sign: &str,
+        number: impl Display,
+        unit: &str,
+    ) -> std::fmt::Result {
+        let separator = self.separator.as_str();
+        let Some(width) = self.tabular else {
+            return pad(f, format_args!("{}{}{}{}", sign, number, separator, unit));
+        };
+        let mut measure = Measure {
+            decimal_separator: self.decimal_separator,
+            integer: 0,
+            fraction: 0,
+        };
+        // Measuring can't fail.
+        let _ = write!(measure, "{}{}", sign, number);
+        let fraction_width = match self.max_decimals() {
+            0 => 0,
+            decimals => decimals + 1,
+        };
+        let unit_len = unit.chars().count();
+        let unit_width = self
+            .units()
+            .iter()
+            .map(|unit| self.unit_name(*unit, false).chars().count())
+            .fold(unit_len, usize::max);
+        let fixed = fraction_width + separator.chars().count() + unit_width;
+        let integer_pad = width.saturating_sub(fixed).saturating_sub(measure.integer);
+        let fraction_pad = fraction_width.saturating_sub(measure.fraction);
+        pad(
+            f,
+            format_args!(
+                "{:integer_pad$}{}{}{:fraction_pad$}{}{}{:unit_pad$}",
+                "",
+                sign,
+                number,
+                "",
+                separator,
+                unit,
+                "",
+                unit_pad = unit_width - unit_len,
+            ),
+        )
+    }
+
+    /// Returns the most decimals any unit can be shown with.
+    fn max_decimals(&self) -> usize {
+        match (self.preset, self.significant_figures) {
+            (Preset::WindowsExplorer, _) => 2,
+            (_, Some(figures)) => figures.saturating_sub(1),
+            (_, None) => self.unit_precision.iter().flatten().fold(self.precision, |a, b| a.max(*b)),
+        }
+    }
+
+    /// Picks the unit to show the size in, before any rounding moves it up.
+    fn auto_unit(&self, size: Size) -> Units {
+        match self.preset {
+            Preset::WindowsExplorer => explorer_unit(size),
+            _ => self.clamp_unit(self.pick_unit(size)),
+        }
+    }
+
+    /// Rounds the size in `unit` to the decimals shown for it. When `promote` is
+    /// set and rounding reaches the next unit of the base, as with "1000.0 kB",
+    /// the size is rounded in that unit instead.
+    fn amount(&self, size: Size, unit: Units, promote: bool) -> (Units, Scaled) {
+        let bytes = size.0 as u128;
+        if self.preset == Preset::WindowsExplorer {
+            // Explorer never moves up a unit after rounding, so it shows "1,024 KB".
+            let amount = if unit < Units::Mebibytes {
+                Scaled::rounded(bytes, unit, 0, Rounding::Ceil)
+            } else {
+                significant(bytes, unit, 3, Rounding::Floor)
+            };
+            return (unit, amount);
+        }
+        let amount = match self.significant_figures {
+            None => {
+                let decimals = self.unit_precision[index_in(Units::ALL, unit)];
+                let decimals = decimals.unwrap_or(self.precision);
+                Scaled::rounded(bytes, unit, decimals, self.rounding)
+            }
+            Some(figures) => significant(bytes, unit, figures, self.rounding),
+        };
+
+        match promotion_past(self.units(), unit, &amount, self.switch_threshold) {
+            Some(larger) if promote && self.max_unit.is_none_or(|max| larger <= max) => {
+                self.amount(size, larger, false)
+            }
+            _ => (unit, amount),
+        }
+    }
+
+    /// Returns the units of the base, smallest first.
+    fn units(&self) -> &'static [Units] {
+        match self.base {
+            Base::Decimal => Units::decimal(),
+            Base::Binary => Units::binary(),
+        }
+    }
+
+    /// Picks the largest unit of the base that the size has `switch_threshold` of.
+    fn pick_unit(&self, size: Size) -> Units {
+        let bytes = size.0 as u128 * 1000;
+        let threshold = self.switch_threshold as u128;
+        let mut units = self.units().iter().rev();
+        let unit = units.find(|unit| bytes >= unit.bytes() as u128 * threshold);
+        unit.copied().unwrap_or(Units::Bytes)
+    }
+
+    /// Moves `unit` into the range set by `min_unit` and `max_unit`.
+    fn clamp_unit(&self, mut unit: Units) -> Units {
+        if let Some(min) = self.min_unit {
+            unit = unit.max(min);
+        }
+        if let Some(max) = self.max_unit {
+            unit = unit.min(max);
+        }
+        unit
+    }
+
+    /// Returns the symbol `unit` is overridden with, unless it is named in full.
+    fn symbol_override(&self, unit: Units) -> Option<&'static str> {
+        match (self.preset, self.unit_style) {
+            (Preset::None, UnitStyle::Long) => None,
+            _ => self.symbol_overrides[index_in(Units::ALL, unit)],
+        }
+    }
+
+    fn unit_name(&self, unit: Units, singular: bool) -> &'static str {
+        if let Some(symbol) = self.symbol_override(unit) {
+            return symbol;
+        }
+        match self.preset {
+            Preset::None => {}
+            Preset::WindowsExplorer => return explorer_unit_name(unit),
+            Preset::Gnu => return gnu_suffix(unit),
+        }
+        let (symbol, name_singular, name_plural) = self.names(unit);
+        match self.unit_style {
+            UnitStyle::Symbol => symbol,
+            UnitStyle::Long if singular => name_singular,
+            UnitStyle::Long => name_plural,
+        }
+    }
+
+    /// Returns the symbol, singular name and plural name of `unit`.
+    #[cfg(not(feature = "i18n"))]
+    fn names(&self, unit: Units) -> (&'static str, &'static str, &'static str) {
+        (unit.symbol(), unit.name_singular(), unit.name_plural())
+    }
+
+    /// Returns the symbol, singular name and plural name of `unit` in the `locale`.
+    #[cfg(feature = "i18n")]
+    fn names(&self, unit: Units) -> (&'static str, &'static str, &'static str) {
+        let locale = self.locale;
+        (locale.symbol(unit), locale.name_singular(unit), locale.name_plural(unit))
+    }
+}
+
+/// Counts the characters written before and after a decimal separator, which is
+/// counted with the fraction.
+struct Measure {
+    decimal_separator: char,
+    integer: usize,
+    fraction: usize,
+}
+
+impl Write for Measure {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        for c in s.chars() {
+            if self.fraction > 0 || c == self.decimal_separator {
+                self.fraction += 1;
+            } else {
+                self.integer += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A size written by a `SizeFormatter`, as returned by `SizeFormatter::display`.
+struct FormattedSize<'a> {
+    formatter: &'a SizeFormatter,
+    size: Size,
+    /// The unit to write the size in, or `None` to pick one.
+    unit: Option<Units>,
+}
+
+impl Display for FormattedSize<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.formatter.write(f, self.size, self.unit)
+    }
+}
+
+/// A size written with the default formatting of a base, or in a fixed unit, as
+/// returned by `Size::display`, `Size::display_decimal`, `Size::display_binary`
+/// and `Size::display_as`.
+///
+/// Unlike `FormattedSize`, this takes its precision from the format string. The
+/// alternate flag `{:#}` switches the default base to binary units, or names a
+/// fixed unit in full.
+pub(crate) struct SizeDisplay {
+    pub(crate) size: Size,
+    /// The base to pick a unit in, or `None` for `DEFAULT_BASE`, which `{:#}` turns binary.
+    pub(crate) base: Option<Base>,
+    pub(crate) unit: Option<Units>,
+}
+
+impl Display for SizeDisplay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let base = match self.base {
+            Some(base) => base,
+            None if self.unit.is_none() && f.alternate() => Base::Binary,
+            None => DEFAULT_BASE,
+        };
+        let formatter = SizeFormatter::new()
+            .base(base)
+            .precision(f.precision().unwrap_or(1))
+            .long_names(self.unit.is_some() && f.alternate());
+        formatter.write(f, self.size, self.unit)
+    }
+}
+
+/// A byte count divided by a unit and rounded to a fixed number of decimals,
+/// using only integer math.
+///
+/// The fraction digits come from long division of the remainder, so any number
+/// of decimals is exact without needing a wider integer.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Scaled {
+    /// The integer part, including any carry from rounding up.
+    whole: u128,
+    /// The leftover bytes after taking out `whole` units, before rounding.
+    remainder: u64,
+    unit_bytes: u64,
+    /// How many fraction digits to write.
+    decimals: usize,
+    round_up: bool,
+    /// The last fraction digit that isn't a 9, which is where a round up lands.
+    /// When there is none, the round up carries into `whole` instead.
+    carry_digit: Option<usize>,
+    /// How many fraction digits are left once trailing zeros are dropped, after rounding.
+    significant: usize,
+    /// Written between every group of three digits of `whole`.
+    thousands_separator: Option<char>,
+    /// Written between `whole` and the fraction.
+    decimal_separator: char,
+}
+
+impl Scaled {
+    /// Rounds half up, like `Rounding::HalfUp`.
+    pub(crate) fn new(bytes: u128, unit: Units, decimals: usize) -> Self {
+        Self::rounded(bytes, unit, decimals, Rounding::HalfUp)
+    }
+
+    pub(crate) fn rounded(bytes: u128, unit: Units, decimals: usize, rounding: Rounding) -> Self {
+        // A fraction of a byte is meaningless, so bytes are always whole.
+        let decimals = if unit == Units::Bytes { 0 } else { decimals };
+        let unit_bytes = unit.bytes();
+        let mut whole = bytes / unit_bytes as u128;
+        let remainder = (bytes % unit_bytes as u128) as u64;
+
+        let mut rest = remainder as u128;
+        let mut carry_digit = None;
+        let mut significant = 0;
+        // Only needed for ties, where the loop below runs to the end.
+        let mut last_digit = whole % 10;
+        for i in 0..decimals {
+            if rest == 0 {
+                // Every digit from here on is a zero, so there's nothing to round.
+                break;
+            }
+            let digit = rest * 10 / unit_bytes as u128;
+            rest = rest * 10 % unit_bytes as u128;
+            if digit != 9 {
+                carry_digit = Some(i);
+            }
+            if digit != 0 {
+                significant = i + 1;
+            }
+            last_digit = digit;
+        }
+
+        let unit_bytes_wide = unit_bytes as u128;
+        let round_up = match rounding {
+            Rounding::HalfUp => rest * 2 >= unit_bytes_wide,
+            Rounding::HalfEven => {
+                rest * 2 > unit_bytes_wide || (rest * 2 == unit_bytes_wide && last_digit % 2 == 1)
+            }
+            Rounding::Floor => false,
+            Rounding::Ceil => rest != 0,
+        };
+        if round_up {
+            // The digit the carry lands on is the last non-zero one, since every
+            // digit after it rolls over from 9 to 0.
+            match carry_digit {
+                Some(carry) => significant = carry + 1,
+                None => {
+                    whole += 1;
+                    significant = 0;
+                }
+            }
+        }
+        Self {
+            whole,
+            remainder,
+            unit_bytes,
+            decimals,
+            round_up,
+            carry_digit,
+            significant,
+            thousands_separator: None,
+            decimal_separator: '.',
+        }
+    }
+
+    /// Returns true if the rounded amount is exactly one.
+    pub(crate) fn is_one(&self) -> bool {
+        self.whole == 1 && self.significant == 0
+    }
+
+    /// Drops the fraction if the amount is a whole number before rounding.
+    pub(crate) fn exact_integer(self) -> Self {
+        if self.remainder == 0 {
+            Self { decimals: 0, ..self }
+        } else {
+            self
+        }
+    }
+
+    /// Splits the integer part into groups of three digits with `thousands`,
+    /// and writes `decimal` before the fraction.
+    pub(crate) fn separators(self, thousands: Option<char>, decimal: char) -> Self {
+        Self {
+            thousands_separator: thousands,
+            decimal_separator: decimal,
+            ..self
+        }
+    }
+
+    /// Drops the fraction if every digit of it is zero.
+    pub(crate) fn trim_zero_fraction(self) -> Self {
+        if self.significant == 0 {
+            Self { decimals: 0, ..self }
+        } else {
+            self
+        }
+    }
+
+    /// Drops every zero at the end of the fraction.
+    pub(crate) fn trim_zeros(self) -> Self {
+        Self {
+            decimals: self.significant,
+            ..self
+        }
+    }
+}
+
+impl Display for Scaled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.thousands_separator {
+            Some(separator) => write_grouped(f, self.whole, separator)?,
+            None => write!(f, "{}", self.whole)?,
+        }
+        if self.decimals == 0 {
+            return Ok(());
+        }
+        f.write_char(self.decimal_separator)?;
+        let mut rest = self.remainder as u128;
+        for i in 0..self.decimals {
+            let digit = rest * 10 / self.unit_bytes as u128;
+            rest = rest * 10 % self.unit_bytes as u128;
+            let digit = match self.carry_digit {
+                _ if !self.round_up => digit,
+                Some(carry) if i < carry => digit,
+                Some(carry) if i == carry => digit + 1,
+                _ => 0,
+            };
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+/// Rounds `bytes` in `unit` to `figures` significant figures, keeping every digit
+/// of the integer part.
+fn significant(bytes: u128, unit: Units, figures: usize, rounding: Rounding) -> Scaled {
+    let digits = integer_digits(bytes / unit.bytes() as u128);
+    let amount = Scaled::rounded(bytes, unit, figures.saturating_sub(digits), rounding);
+    if integer_digits(amount.whole) > digits {
+        // Rounding carried into a new digit, which takes the place of a decimal.
+        let decimals = figures.saturating_sub(digits + 1);
+        Scaled::rounded(bytes, unit, decimals, rounding)
+    } else {
+        amount
+    }
+}
+
+/// Returns the unit Windows Explorer shows `size` in: kilobytes below a megabyte,
+/// and otherwise the binary unit it has less than 1000 of.
+fn explorer_unit(size: Size) -> Units {
+    let units = Units::binary();
+    let unit = size.get_units_in(Base::Binary).max(Units::Kibibytes);
+    let index = index_in(units, unit);
+    match units.get(index + 1) {
+        Some(&larger) if unit >= Units::Mebibytes && size.0 / unit.bytes() >= 1000 => larger,
+        _ => unit,
+    }
+}
+
+/// Returns the JEDEC symbol Windows Explorer labels `unit` with, or "bytes".
+fn explorer_unit_name(unit: Units) -> &'static str {
+    match unit {
+        Units::Bytes => "bytes",
+        _ => match unit.jedec_symbol() {
+            Some(symbol) => symbol,
+            None => unit.symbol(),
+        },
+    }
+}
+
+/// Returns the suffix GNU coreutils writes after a number of `unit`, which is
+/// nothing for bytes.
+fn gnu_suffix(unit: Units) -> &'static str {
+    match unit {
+        Units::Bytes => "",
+        Units::Kilobytes => "k",
+        Units::Kibibytes => "K",
+        Units::Megabytes | Units::Mebibytes => "M",
+        Units::Gigabytes | Units::Gibibytes => "G",
+        Units::Terabytes | Units::Tebibytes => "T",
+        Units::Petabytes | Units::Pebibytes => "P",
+        Units::Exabytes | Units::Exbibytes => "E",
+        _ => unit.symbol(),
+    }
+}
+
+/// Checks that a formatter's unit bounds don't cross.
+const fn check_unit_bounds(min: Option<Units>, max: Option<Units>) {
+    if let (Some(min), Some(max)) = (min, max) {
+        debug_assert!(min.bytes() <= max.bytes(), "min_unit is larger than max_unit");
+    }
+}
+
+/// Returns the unit after `unit` in `units` (ordered smallest first), if `amount`
+/// of `unit` rounded up to a whole one of it.
+pub(crate) fn promotion(units: &[Units], unit: Units, amount: &Scaled) -> Option<Units> {
+    promotion_past(units, unit, amount, 1000)
+}
+
+/// Like `promotion`, but only once `amount` rounded up to `threshold` thousandths
+/// of the next unit.
+fn promotion_past(units: &[Units], unit: Units, amount: &Scaled, threshold: u64) -> Option<Units> {
+    let i = units.iter().position(|u| *u == unit)?;
+    let larger = *units.get(i + 1)?;
+    let ratio = (larger.bytes() / unit.bytes()) as u128;
+    (amount.whole * 1000 >= ratio * threshold as u128).then_some(larger)
+}
+
+/// Returns how many digits `n` has, counting zero as one digit.
+fn integer_digits(n: u128) -> usize {
+    n.checked_ilog10().map_or(1, |log| log as usize + 1)
+}
+
+/// Writes `n` with `separator` between every group of three digits, counting from the right.
+fn write_grouped(f: &mut std::fmt::Formatter<'_>, mut n: u128, separator: char) -> std::fmt::Result {
+    // u128::MAX has 39 digits.
+    let mut digits = [0u8; 39];
+    let mut len = 0;
+    loop {
+        digits[len] = (n % 10) as u8;
+        len += 1;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    for i in (0..len).rev() {
+        write!(f, "{}", digits[i])?;
+        if i > 0 && i % 3 == 0 {
+            f.write_char(separator)?;
+        }
+    }
+    Ok(())
+}
+
+/// Counts the characters written to it, to measure output before padding it.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes `args` padded to the formatter's width with its fill and alignment,
+/// aligning left by default like `str`.
+///
+/// Unlike `Formatter::pad`, this doesn't truncate to the precision, which sizes
+/// use for their number of decimals instead.
+pub(crate) fn pad(f: &mut std::fmt::Formatter<'_>, args: std::fmt::Arguments<'_>) -> std::fmt::Result {
+    let Some(width) = f.width() else {
+        return f.write_fmt(args);
+    };
+    let mut len = CharCount(0);
+    len.write_fmt(args)?;
+
+    let padding = width.saturating_sub(len.0);
+    let (before, after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_fmt(args)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use std::iter::Sum;
+
+use crate::Size;
+
+/// Sums an iterator of sizes, with the same overflow behavior as `Size + Size`.
+/// An empty iterator sums to `Size::ZERO`.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// struct Entry {
+///     size: Size,
+/// }
+///
+/// let listing = vec![
+///     Entry { size: Size::from(54222u64) },
+///     Entry { size: Size::from(1_200_000u64) },
+/// ];
+///
+/// let total: Size = listing.iter().map(|e| e.size).sum();
+/// assert_eq!(total.to_decimal_string(), "1.3 MB");
+/// # let sizes = vec![Size::from(1u64), Size::from(2u64)];
+/// # assert_eq!(sizes.iter().sum::<Size>(), Size::from(3u64));
+/// # assert_eq!(Vec::<Size>::new().into_iter().sum::<Size>(), Size::ZERO);
+/// # assert_eq!(Vec::<Size>::new().iter().sum::<Size>(), Size::ZERO);
+/// ```
+impl Sum for Size {
+    fn sum<I: Iterator<Item = Size>>(iter: I) -> Size {
+        iter.fold(Size::ZERO, |total, size| total + size)
+    }
+}
+
+impl<'a> Sum<&'a Size> for Size {
+    fn sum<I: Iterator<Item = &'a Size>>(iter: I) -> Size {
+        iter.copied().sum()
+    }
+}
+
+/// Collects an iterator of sizes into their total, like `Sum`.
+///
+/// ```rust
+/// # use repr_size::*;
+/// let total: Size = [Size::KIB, Size::KIB].into_iter().collect();
+/// assert_eq!(total, Size::from(2048u64));
+/// # assert_eq!(std::iter::empty::<Size>().collect::<Size>(), Size::ZERO);
+/// ```
+impl FromIterator<Size> for Size {
+    fn from_iter<I: IntoIterator<Item = Size>>(iter: I) -> Size {
+        iter.into_iter().sum()
+    }
+}
+
+/// Collects an iterator of raw byte counts into their total.
+///
+/// ```rust
+/// # use repr_size::*;
+/// let chunks: [&[u8]; 2] = [b"hello", b"world"];
+///
+/// let total: Size = chunks.iter().map(|c| c.len()).collect();
+/// assert_eq!(total, Size::from(10u64));
+/// # assert_eq!(std::iter::empty::<usize>().collect::<Size>(), Size::ZERO);
+/// ```
+impl FromIterator<usize> for Size {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Size {
+        iter.into_iter().map(Size::from).sum()
+    }
+}
+
+impl Size {
+    /// Sums sizes from untrusted input, returning `None` as soon as the total
+    /// would be larger than `Size::MAX` instead of panicking.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let manifest = [Size::MAX, Size::from(1u64), Size::from(2u64)];
+    ///
+    /// assert_eq!(Size::try_sum(manifest), None);
+    /// assert_eq!(Size::try_sum([Size::KIB, Size::KIB]), Some(Size::from(2048u64)));
+    /// # assert_eq!(Size::try_sum([]), Some(Size::ZERO));
+    /// # assert_eq!(Size::try_sum([Size::MAX, Size::ZERO]), Some(Size::MAX));
+    /// # assert_eq!(Size::try_sum(std::iter::repeat(Size::PB).take(100_000)), None);
+    /// ```
+    pub fn try_sum(iter: impl IntoIterator<Item = Size>) -> Option<Size> {
+        iter.into_iter()
+            .try_fold(Size::ZERO, |total, size| total.checked_add(size))
+    }
+
+    /// Sums sizes, clamping the total at `Size::MAX` instead of overflowing.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let manifest = [Size::MAX, Size::from(1u64), Size::from(2u64)];
+    ///
+    /// assert_eq!(Size::saturating_sum(manifest), Size::MAX);
+    /// assert_eq!(Size::saturating_sum([Size::KIB, Size::KIB]), Size::from(2048u64));
+    /// # assert_eq!(Size::saturating_sum([]), Size::ZERO);
+    /// ```
+    pub fn saturating_sum(iter: impl IntoIterator<Item = Size>) -> Size {
+        iter.into_iter()
+            .fold(Size::ZERO, |total, size| total.saturating_add(size))
+    }
+}
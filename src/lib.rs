@@ -5,35 +5,127 @@
 //! # Quickstart
 //! ```rust
 //! # use repr_size::*;
-//! let my_file_size = Size::from(54222);
+//! let my_file_size = Size::from(54222u64);
 //!
 //! println!("{}", my_file_size); // "54.2 kB"
-//! # assert_eq!("54.2 kB", my_file_size.to_string());
-//! println!("{}", my_file_size.to_si_string()); // "53.0 KiB"
-//! # assert_eq!("53.0 KiB", my_file_size.to_si_string());
+//! # if !cfg!(feature = "default-binary") {
+//! #     assert_eq!("54.2 kB", my_file_size.to_string());
+//! # }
+//! println!("{:#}", my_file_size); // "53.0 KiB"
+//! # assert_eq!("53.0 KiB", format!("{:#}", my_file_size));
+//! println!("{}", my_file_size.to_decimal_string()); // "54.2 kB"
+//! # assert_eq!("54.2 kB", my_file_size.to_decimal_string());
+//! println!("{}", my_file_size.to_binary_string()); // "53.0 KiB"
+//! # assert_eq!("53.0 KiB", my_file_size.to_binary_string());
 //! println!("{}", my_file_size.repr(Units::Bytes)); // "54222 B"
-//! # assert_eq!("54222.0 B", my_file_size.repr(Units::Bytes));
+//! # assert_eq!("54222 B", my_file_size.repr(Units::Bytes));
 //! ```
 //!
+//! `Size` prints with decimal units (kB, MB, ...) by default, and with binary
+//! units (KiB, MiB, ...) with the alternate flag `{:#}` or the `default-binary`
+//! feature. A precision such as
+//! `{:.2}` sets the number of decimals, and width and alignment work as for `str`.
+//!
+//! Decimal units are the SI prefixes, in powers of 1000, and binary units are the
+//! IEC ones, in powers of 1024. `to_si_string` and `get_si_units` gave binary
+//! units despite their names, and are deprecated in favor of `to_binary_string`
+//! and `get_binary_unit`.
+//!
 //! # Features
-//! `serde` - enables serialization/deserialization of `Size` <-> usize
+//! `serde` - enables serialization/deserialization of `Size` <-> u64, and adds the
+//! `serde_string` and `serde_string_option` modules for sizes written as strings
+//! such as "25 MiB", `serde_flexible` for sizes that may come as numbers or strings,
+//! and `serde_struct` for objects like `{ "value": 1536, "unit": "MiB" }`
+//!
+//! `i18n` - adds `Locale`, for unit names and symbols in a handful of languages
+//!
+//! `default-binary` - makes `Display` and `to_string` on `Size`, `Size128` and
+//! `SizeDelta` use binary units (KiB, MiB, ...). Explicit methods like
+//! `to_decimal_string`, `repr` and `SizeFormatter` are unaffected. Since features
+//! are unified, binary units win if any crate in the build turns this on
+//!
+//! `float` (on by default) - adds the `f64` conversions and ratios, such as
+//! `Size::mul_f64` and `Size::percent_of`. Formatting and parsing never use
+//! floats, so turning this off leaves an integer-only crate
 
+use std::ffi::OsStr;
 use std::fmt::Display;
+use std::num::NonZeroUsize;
+
+mod delta;
+mod error;
+mod format;
+mod iter;
+#[cfg(feature = "i18n")]
+mod locale;
+mod ops;
+mod parse;
+#[cfg(feature = "serde")]
+pub mod serde_flexible;
+#[cfg(feature = "serde")]
+pub mod serde_string;
+#[cfg(feature = "serde")]
+pub mod serde_struct;
+#[cfg(feature = "serde")]
+mod serde_size;
+#[cfg(feature = "serde")]
+pub mod serde_string_option;
+mod size128;
+mod stats;
+#[cfg(feature = "float")]
+pub use delta::SizeChange;
+pub use delta::SizeDelta;
+#[cfg(feature = "float")]
+pub use error::FloatSizeError;
+pub use error::SizeConversionError;
+pub use format::{Rounding, Separator, SizeFormatter, UnitStyle};
+#[cfg(feature = "i18n")]
+pub use locale::Locale;
+pub use parse::{Grouping, ParseSizeError, SizeParser};
+pub use size128::Size128;
+pub use stats::{SizeHistogram, SizeStats};
 
-#[derive(Debug, Clone, Copy, PartialOrd, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 /// Different units available for representing a Size.
 ///
+/// Units are ordered by how many bytes they represent, regardless of their
+/// base, so `Units::Kilobytes < Units::Kibibytes < Units::Megabytes`. No two
+/// units are the same size, so this ordering never ties.
+///
 /// # Usage
 /// ```rust
 /// # use repr_size::{Size, Units};
-/// let my_file_size = Size::from(2300);
+/// let my_file_size = Size::from(2300u64);
 /// println!("{}", my_file_size); // 2.3 kB
 /// println!("{}", my_file_size.to_string()); // 2.3 kB
-/// println!("{}", my_file_size.to_si_string()); // 2.2 KiB
+/// println!("{}", my_file_size.to_binary_string()); // 2.2 KiB
 /// println!("{}", my_file_size.repr(Units::Bytes)); // 2300 B
 ///
 /// let twenty_three_kilobytes = Size::from_units(23, Units::Kilobytes);
 /// ```
+///
+/// ```rust
+/// # use repr_size::Units;
+/// # use std::collections::BTreeMap;
+/// let mut units = vec![Units::Gibibytes, Units::Bytes, Units::Megabits, Units::Gigabytes, Units::Kibibytes];
+/// units.sort();
+/// assert_eq!(units, [Units::Bytes, Units::Kibibytes, Units::Megabits, Units::Gigabytes, Units::Gibibytes]);
+/// # let mut all = Units::ALL.to_vec();
+/// # all.reverse();
+/// # all.sort();
+/// # assert_eq!(all, Units::ALL);
+/// # assert!(Units::Kilobytes < Units::Kibibytes && Units::Kibibytes < Units::Megabytes);
+/// # assert!(Units::Tebibits < Units::Terabytes);
+///
+/// let mut totals = BTreeMap::new();
+/// *totals.entry(Units::Mebibytes).or_insert(0) += 2;
+/// *totals.entry(Units::Kilobytes).or_insert(0) += 1;
+/// *totals.entry(Units::Mebibytes).or_insert(0) += 3;
+/// assert_eq!(totals.into_iter().collect::<Vec<_>>(), [(Units::Kilobytes, 1), (Units::Mebibytes, 5)]);
+/// # let set: std::collections::HashSet<Units> = Units::ALL.iter().copied().collect();
+/// # assert_eq!(set.len(), Units::ALL.len());
+/// ```
+#[non_exhaustive]
 pub enum Units {
     /// Base unit. Equal to 1 byte, or 8 bits, or sizeof(u8), or 1/1000th of a Kilobyte.
     Bytes,
@@ -62,158 +154,2527 @@ pub enum Units {
     Petabytes,
     /// (PiB) 1024^5 bytes.
     Pebibytes,
+
+    /// (EB) 1000^6 bytes.
+    Exabytes,
+    /// (EiB) 1024^6 bytes.
+    Exbibytes,
+
+    /// (kbit) 1000 bits, or 125 bytes.
+    Kilobits,
+    /// (Kibit) 1024 bits, or 128 bytes.
+    Kibibits,
+
+    /// (Mbit) 1000^2 bits, or 125,000 bytes.
+    Megabits,
+    /// (Mibit) 1024^2 bits, or 131,072 bytes.
+    Mebibits,
+
+    /// (Gbit) 1000^3 bits.
+    Gigabits,
+    /// (Gibit) 1024^3 bits.
+    Gibibits,
+
+    /// (Tbit) 1000^4 bits.
+    Terabits,
+    /// (Tibit) 1024^4 bits.
+    Tebibits,
 }
 
+/// The family of units to pick from: powers of 1000 (kB, MB, ...) or powers of 1024 (KiB, MiB, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base {
+    /// Powers of 1000, such as `Units::Kilobytes`.
+    Decimal,
+    /// Powers of 1024, such as `Units::Kibibytes`.
+    Binary,
+}
+
+/// The base `Display` and `Size::to_string` pick units from.
+const DEFAULT_BASE: Base = if cfg!(feature = "default-binary") {
+    Base::Binary
+} else {
+    Base::Decimal
+};
+
+/// Every power-of-1000 unit, smallest first.
+const DECIMAL_UNITS: &[Units] = &[
+    Units::Bytes,
+    Units::Kilobytes,
+    Units::Megabytes,
+    Units::Gigabytes,
+    Units::Terabytes,
+    Units::Petabytes,
+    Units::Exabytes,
+];
+
+/// Every power-of-1024 unit, smallest first.
+const BINARY_UNITS: &[Units] = &[
+    Units::Bytes,
+    Units::Kibibytes,
+    Units::Mebibytes,
+    Units::Gibibytes,
+    Units::Tebibytes,
+    Units::Pebibytes,
+    Units::Exbibytes,
+];
+
+/// Every power-of-1000 bit unit, smallest first.
+const DECIMAL_BIT_UNITS: &[Units] = &[
+    Units::Kilobits,
+    Units::Megabits,
+    Units::Gigabits,
+    Units::Terabits,
+];
+
+/// Every power-of-1024 bit unit, smallest first.
+const BINARY_BIT_UNITS: &[Units] = &[
+    Units::Kibibits,
+    Units::Mebibits,
+    Units::Gibibits,
+    Units::Tebibits,
+];
+
 impl Units {
-    /// Returns the amount of bytes this type represents, ie Units::Kilobytes == 1024
-    pub fn bytes(&self) -> usize {
-        match self {
-            Self::Bytes => 1,
-            Self::Kilobytes => 1000,
-            Self::Kibibytes => 1024,
-            Self::Megabytes => 1000usize.pow(2),
-            Self::Mebibytes => 1024usize.pow(2),
-            Self::Gigabytes => 1000usize.pow(3),
-            Self::Gibibytes => 1024usize.pow(3),
-            Self::Terabytes => 1000usize.pow(4),
-            Self::Tebibytes => 1024usize.pow(4),
-            Self::Petabytes => 1000usize.pow(5),
-            Self::Pebibytes => 1024usize.pow(5),
+    /// Every unit, ordered from smallest to largest.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::ALL.first(), Some(&Units::Bytes));
+    /// assert_eq!(Units::ALL.last(), Some(&Units::Exbibytes));
+    ///
+    /// for pair in Units::ALL.windows(2) {
+    ///     assert!(pair[0].bytes() < pair[1].bytes());
+    /// }
+    /// for unit in Units::ALL {
+    ///     assert_eq!(Size::from(*unit).bytes(), unit.bytes());
+    ///     assert_eq!(unit.to_string().parse::<Units>(), Ok(*unit));
+    ///     assert_eq!(Units::ALL.iter().filter(|u| u.to_string() == unit.to_string()).count(), 1);
+    /// }
+    /// # assert_eq!(Units::ALL.len(), 21);
+    /// ```
+    pub const ALL: &'static [Units] = &[
+        Units::Bytes,
+        Units::Kilobits,
+        Units::Kibibits,
+        Units::Kilobytes,
+        Units::Kibibytes,
+        Units::Megabits,
+        Units::Mebibits,
+        Units::Megabytes,
+        Units::Mebibytes,
+        Units::Gigabits,
+        Units::Gibibits,
+        Units::Gigabytes,
+        Units::Gibibytes,
+        Units::Terabits,
+        Units::Tebibits,
+        Units::Terabytes,
+        Units::Tebibytes,
+        Units::Petabytes,
+        Units::Pebibytes,
+        Units::Exabytes,
+        Units::Exbibytes,
+    ];
+
+    /// Returns the power-of-1000 byte units, smallest first, starting with `Units::Bytes`.
+    /// These are the units `Size::get_units` picks from; bit units aren't included.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::decimal()[..3], [Units::Bytes, Units::Kilobytes, Units::Megabytes]);
+    /// # for pair in Units::decimal().windows(2) {
+    /// #     assert_eq!(pair[0].bytes() * 1000, pair[1].bytes());
+    /// # }
+    /// # assert_eq!(Units::decimal().last(), Some(&Units::Exabytes));
+    /// ```
+    pub const fn decimal() -> &'static [Units] {
+        DECIMAL_UNITS
+    }
+
+    /// Returns the power-of-1024 byte units, smallest first, starting with `Units::Bytes`.
+    /// These are the units `Size::get_binary_unit` picks from; bit units aren't included.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::binary()[..3], [Units::Bytes, Units::Kibibytes, Units::Mebibytes]);
+    /// # for pair in Units::binary().windows(2) {
+    /// #     assert_eq!(pair[0].bytes() * 1024, pair[1].bytes());
+    /// # }
+    /// # assert_eq!(Units::binary().last(), Some(&Units::Exbibytes));
+    /// ```
+    pub const fn binary() -> &'static [Units] {
+        BINARY_UNITS
+    }
+
+    /// Returns the byte unit that is exactly `bytes` large, such as `Units::Mebibytes`
+    /// for 1048576, or `None` if there isn't one. Bit units are never returned.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::try_from_bytes(1_048_576), Some(Units::Mebibytes));
+    /// assert_eq!(Units::try_from_bytes(1000), Some(Units::Kilobytes));
+    /// assert_eq!(Units::try_from_bytes(1), Some(Units::Bytes));
+    /// assert_eq!(Units::try_from_bytes(4096), None);
+    /// # assert_eq!(Units::try_from_bytes(125), None);
+    /// # assert_eq!(Units::try_from_bytes(0), None);
+    ///
+    /// // Decimal and binary units never share a byte count, so the lookup is unambiguous.
+    /// for unit in Units::ALL.iter().filter(|unit| !unit.is_bits()) {
+    ///     assert_eq!(Units::try_from_bytes(unit.bytes()), Some(*unit));
+    ///     assert_eq!(Units::ALL.iter().filter(|other| other.bytes() == unit.bytes()).count(), 1);
+    /// }
+    /// ```
+    pub const fn try_from_bytes(bytes: u64) -> Option<Units> {
+        let unit = Units::closest_to(bytes);
+        if unit.bytes() == bytes {
+            Some(unit)
+        } else {
+            None
         }
     }
-}
 
-impl Display for Units {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Bytes => "B",
-                Self::Kilobytes => "kB",
-                Self::Kibibytes => "KiB",
-                Self::Megabytes => "MB",
-                Self::Mebibytes => "MiB",
-                Self::Gigabytes => "GB",
-                Self::Gibibytes => "GiB",
-                Self::Terabytes => "TB",
-                Self::Tebibytes => "TiB",
-                Self::Petabytes => "PB",
-                Self::Pebibytes => "PiB",
+    /// Returns the largest byte unit of either base that isn't larger than `bytes`,
+    /// falling back to `Units::Bytes`. Bit units are never returned.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::closest_to(1010), Units::Kilobytes);
+    /// assert_eq!(Units::closest_to(1030), Units::Kibibytes);
+    /// assert_eq!(Units::closest_to(4096), Units::Kibibytes);
+    /// assert_eq!(Units::closest_to(0), Units::Bytes);
+    /// # assert_eq!(Units::closest_to(999), Units::Bytes);
+    /// # assert_eq!(Units::closest_to(u64::MAX), Units::Exbibytes);
+    /// # for unit in Units::ALL.iter().filter(|unit| !unit.is_bits()) {
+    /// #     assert_eq!(Units::closest_to(unit.bytes()), *unit);
+    /// # }
+    /// ```
+    pub const fn closest_to(bytes: u64) -> Units {
+        let mut closest = Units::Bytes;
+        let mut i = 0;
+        while i < Units::ALL.len() {
+            let unit = Units::ALL[i];
+            if unit.bytes() > bytes {
+                break;
             }
-        )
+            if !unit.is_bits() {
+                closest = unit;
+            }
+            i += 1;
+        }
+        closest
     }
-}
 
-/// Represents an amount of bytes.
-/// Create these by using `Size::from(usize)` or `Size::from_units(usize, Units)`
-#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[cfg_attr(feature = "serde", serde(from = "usize", into = "usize"))]
-pub struct Size(usize);
+    /// Returns the smallest unit, `Units::Bytes`.
+    pub const fn smallest() -> Units {
+        Units::Bytes
+    }
 
-impl Display for Size {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.to_string())
+    /// Returns the largest byte unit of the given base.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::largest(Base::Decimal), Units::Exabytes);
+    /// assert_eq!(Units::largest(Base::Binary), Units::Exbibytes);
+    /// ```
+    pub const fn largest(base: Base) -> Units {
+        match base {
+            Base::Decimal => Units::Exabytes,
+            Base::Binary => Units::Exbibytes,
+        }
     }
-}
 
-impl Size {
-    /// Gets the largest non-SI unit that can represent this number without all significant digits being in the decimal.
+    /// Returns the next larger unit of the same base and kind, or `None` if this
+    /// is the largest one. Bytes step up to kilobytes, and bits stay bits, so
+    /// `Units::Megabits` steps up to `Units::Gigabits`.
     ///
     /// # Usage
     /// ```rust
     /// # use repr_size::*;
-    /// let just_over_one_kilobyte = Size::from(1001);
-    /// let just_under_one_kilobyte = Size::from(999);
+    /// assert_eq!(Units::Kibibytes.next_larger(), Some(Units::Mebibytes));
+    /// assert_eq!(Units::Kilobytes.next_larger(), Some(Units::Megabytes));
+    /// assert_eq!(Units::Bytes.next_larger(), Some(Units::Kilobytes));
+    /// assert_eq!(Units::Exbibytes.next_larger(), None);
+    /// # assert_eq!(Units::Megabits.next_larger(), Some(Units::Gigabits));
+    /// # assert_eq!(Units::Tebibits.next_larger(), None);
     ///
-    /// assert_eq!(just_over_one_kilobyte.get_units(), Units::Kilobytes);
-    /// assert_eq!(just_under_one_kilobyte.get_units(), Units::Bytes);
+    /// // Walking up from kibibytes visits every binary unit, then stops.
+    /// let mut chain = vec![Units::Kibibytes];
+    /// while let Some(next) = chain.last().unwrap().next_larger() {
+    ///     chain.push(next);
+    /// }
+    /// assert_eq!(chain, Units::binary()[1..]);
+    /// # let mut chain = vec![Units::Bytes];
+    /// # while let Some(next) = chain.last().unwrap().next_larger() {
+    /// #     chain.push(next);
+    /// # }
+    /// # assert_eq!(chain, Units::decimal());
     /// ```
-    pub fn get_units(&self) -> Units {
-        match self.0 {
-            x if x < 1000usize => Units::Bytes,
-            x if x < 1000usize.pow(2) => Units::Kilobytes,
-            x if x < 1000usize.pow(3) => Units::Megabytes,
-            x if x < 1000usize.pow(4) => Units::Gigabytes,
-            x if x < 1000usize.pow(5) => Units::Terabytes,
-            _ => Units::Petabytes,
+    pub const fn next_larger(&self) -> Option<Units> {
+        let scale = self.scale();
+        let i = index_in(scale, *self);
+        if i + 1 < scale.len() {
+            Some(scale[i + 1])
+        } else {
+            None
         }
     }
 
-    /// Gets the largest SI unit that can represent this number without all significant digits being in the decimal.
+    /// Returns the next smaller unit of the same base and kind, or `None` if this
+    /// is the smallest one. Both kilobytes and kibibytes step down to bytes, while
+    /// kilobits and kibibits are the smallest bit units.
     ///
     /// # Usage
     /// ```rust
     /// # use repr_size::*;
-    /// let just_over_one_kibibyte = Size::from(1025);
-    /// let just_under_one_kibibyte = Size::from(1022);
+    /// assert_eq!(Units::Megabytes.next_smaller(), Some(Units::Kilobytes));
+    /// assert_eq!(Units::Kibibytes.next_smaller(), Some(Units::Bytes));
+    /// assert_eq!(Units::Bytes.next_smaller(), None);
+    /// # assert_eq!(Units::Gibibits.next_smaller(), Some(Units::Mebibits));
+    /// # assert_eq!(Units::Kilobits.next_smaller(), None);
     ///
-    /// assert_eq!(just_over_one_kibibyte.get_si_units(), Units::Kibibytes);
-    /// assert_eq!(just_under_one_kibibyte.get_si_units(), Units::Bytes);
+    /// // Walking down from the largest unit of either base ends at bytes.
+    /// for base in [Base::Decimal, Base::Binary] {
+    ///     let mut chain = vec![Units::largest(base)];
+    ///     while let Some(next) = chain.last().unwrap().next_smaller() {
+    ///         chain.push(next);
+    ///     }
+    ///     assert_eq!(chain.last(), Some(&Units::smallest()));
+    ///     # chain.reverse();
+    ///     # let expected = match base { Base::Decimal => Units::decimal(), Base::Binary => Units::binary() };
+    ///     # assert_eq!(chain, expected);
+    /// }
+    /// # for unit in Units::ALL {
+    /// #     if let Some(next) = unit.next_larger() {
+    /// #         assert!(next.bytes() > unit.bytes());
+    /// #         if *unit != Units::Bytes {
+    /// #             assert_eq!(next.next_smaller(), Some(*unit));
+    /// #         }
+    /// #     }
+    /// # }
     /// ```
-    pub fn get_si_units(&self) -> Units {
-        match self.0 {
-            x if x < 1024usize => Units::Bytes,
-            x if x < 1024usize.pow(2) => Units::Kibibytes,
-            x if x < 1024usize.pow(3) => Units::Mebibytes,
-            x if x < 1024usize.pow(4) => Units::Gibibytes,
-            x if x < 1024usize.pow(5) => Units::Tebibytes,
-            _ => Units::Pebibytes,
+    pub const fn next_smaller(&self) -> Option<Units> {
+        let scale = self.scale();
+        match index_in(scale, *self) {
+            0 => None,
+            i => Some(scale[i - 1]),
         }
     }
 
-    /// Returns the size represented as an amount and a non-SI unit.
-    pub fn to_string(&self) -> String {
-        let unit = self.get_units();
-        let number = self.0 as f32 / unit.bytes() as f32;
-        format!("{:.1} {}", number, unit)
+    /// The ordered list of units this unit steps through, with bytes counted as decimal.
+    const fn scale(&self) -> &'static [Units] {
+        match (self.is_bits(), self.base()) {
+            (false, 1024) => BINARY_UNITS,
+            (false, _) => DECIMAL_UNITS,
+            (true, 1024) => BINARY_BIT_UNITS,
+            (true, _) => DECIMAL_BIT_UNITS,
+        }
     }
 
-    /// Returns the size represented as an amount and a unit.
-    pub fn to_si_string(&self) -> String {
-        let unit = self.get_si_units();
-        let number = self.0 as f32 / unit.bytes() as f32;
-        format!("{:.1} {}", number, unit)
+    /// Returns the amount of bytes this type represents, ie Units::Kibibytes == 1024
+    ///
+    /// This is a `u64` so that the larger units can be represented on 32-bit targets.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::Kibibytes.bytes(), 1024);
+    /// # assert_eq!(Units::Bytes.bytes(), 1);
+    /// # assert_eq!(Units::Kilobytes.bytes(), 1_000);
+    /// # assert_eq!(Units::Megabytes.bytes(), 1_000_000);
+    /// # assert_eq!(Units::Mebibytes.bytes(), 1_048_576);
+    /// # assert_eq!(Units::Gigabytes.bytes(), 1_000_000_000);
+    /// # assert_eq!(Units::Gibibytes.bytes(), 1_073_741_824);
+    /// # assert_eq!(Units::Terabytes.bytes(), 1_000_000_000_000);
+    /// # assert_eq!(Units::Tebibytes.bytes(), 1_099_511_627_776);
+    /// # assert_eq!(Units::Petabytes.bytes(), 1_000_000_000_000_000);
+    /// # assert_eq!(Units::Pebibytes.bytes(), 1_125_899_906_842_624);
+    /// # assert_eq!(Units::Exabytes.bytes(), 1_000_000_000_000_000_000);
+    /// # assert_eq!(Units::Exbibytes.bytes(), 1_152_921_504_606_846_976);
+    /// assert_eq!(Units::Megabits.bytes(), 125_000);
+    /// # assert_eq!(Units::Kilobits.bytes() * 8, 1_000);
+    /// # assert_eq!(Units::Kibibits.bytes() * 8, 1_024);
+    /// # assert_eq!(Units::Mebibits.bytes() * 8, 1_048_576);
+    /// # assert_eq!(Units::Gigabits.bytes() * 8, 1_000_000_000);
+    /// # assert_eq!(Units::Gibibits.bytes() * 8, 1_073_741_824);
+    /// # assert_eq!(Units::Terabits.bytes() * 8, 1_000_000_000_000);
+    /// # assert_eq!(Units::Tebibits.bytes() * 8, 1_099_511_627_776);
+    /// ```
+    pub const fn bytes(&self) -> u64 {
+        let bytes = (self.base() as u64).pow(self.exponent());
+        if self.is_bits() {
+            // Every bit unit is at least a kilobit, so this never truncates.
+            bytes / 8
+        } else {
+            bytes
+        }
     }
 
-    /// Returns a string representation of the size using
-    /// the given unit of bytes.
+    /// Returns exactly one of this unit's worth of bytes as a `Size`. This is the
+    /// method-call form of `Size::from(unit)`, and is usable in `const` contexts.
+    ///
+    /// # Usage
     /// ```rust
     /// # use repr_size::*;
-    /// let twenty_two_kb = Size::from(22000);
+    /// const LOG_LINE_LIMIT: Size = Units::Mebibytes.size();
     ///
-    /// println!("{}", twenty_two_kb.repr(Units::Bytes)); // "22000 B"
-    /// println!("{}", twenty_two_kb.repr(Units::Kibibytes)); // "21.4 KiB"
+    /// let line = Size::from(2_000_000u64);
+    /// assert!(line > LOG_LINE_LIMIT);
+    /// assert!(line > Units::Mebibytes.into());
+    /// # for unit in Units::ALL {
+    /// #     assert_eq!(unit.size().bytes(), unit.bytes());
+    /// #     assert_eq!(Size::from(*unit), unit.size());
+    /// #     assert_eq!(Size::from_units(1, *unit), unit.size());
+    /// # }
     /// ```
-    pub fn repr(&self, unit: Units) -> String {
-        let number = self.0 as f32 / unit.bytes() as f32;
-        format!("{:.1} {}", number, unit)
+    pub const fn size(&self) -> Size {
+        Size(self.bytes())
     }
 
-    /// Returns a Size derived from unit's byte amount times the number given.
-    pub fn from_units(x: usize, unit: Units) -> Size {
-        Self(x * unit.bytes())
+    /// Returns the number this unit is a power of: 1000 for decimal units and
+    /// 1024 for binary ones. `Units::Bytes` is the zeroth power of both, and
+    /// reports 1000.
+    ///
+    /// For bit units, the power counts bits rather than bytes.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::Mebibytes.base(), 1024);
+    /// assert_eq!(Units::Mebibytes.exponent(), 2);
+    /// assert_eq!(Units::Gigabits.base(), 1000);
+    /// assert_eq!(Units::Gigabits.exponent(), 3);
+    /// # assert_eq!(Units::Bytes.base(), 1000);
+    /// # assert_eq!(Units::Bytes.exponent(), 0);
+    ///
+    /// for unit in Units::ALL {
+    ///     let amount = (unit.base() as u64).pow(unit.exponent());
+    ///     let bits_per_unit = if unit.is_bits() { 1 } else { 8 };
+    ///     assert_eq!(amount * bits_per_unit, unit.bytes() * 8);
+    /// }
+    /// ```
+    pub const fn base(&self) -> u32 {
+        match self {
+            Self::Kibibytes
+            | Self::Mebibytes
+            | Self::Gibibytes
+            | Self::Tebibytes
+            | Self::Pebibytes
+            | Self::Exbibytes
+            | Self::Kibibits
+            | Self::Mebibits
+            | Self::Gibibits
+            | Self::Tebibits => 1024,
+            _ => 1000,
+        }
+    }
+
+    /// Returns the power of `base()` this unit represents, ie Units::Mebibytes == 2
+    pub const fn exponent(&self) -> u32 {
+        match self {
+            Self::Bytes => 0,
+            Self::Kilobytes | Self::Kibibytes | Self::Kilobits | Self::Kibibits => 1,
+            Self::Megabytes | Self::Mebibytes | Self::Megabits | Self::Mebibits => 2,
+            Self::Gigabytes | Self::Gibibytes | Self::Gigabits | Self::Gibibits => 3,
+            Self::Terabytes | Self::Tebibytes | Self::Terabits | Self::Tebibits => 4,
+            Self::Petabytes | Self::Pebibytes => 5,
+            Self::Exabytes | Self::Exbibytes => 6,
+        }
+    }
+
+    /// Returns true for powers of 1024, such as `Units::Kibibytes`. `Units::Bytes`
+    /// is both binary and decimal.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert!(Units::Kibibytes.is_binary());
+    /// assert!(!Units::Kilobytes.is_binary());
+    /// assert!(Units::Bytes.is_binary() && Units::Bytes.is_decimal());
+    /// # assert!(Units::Mebibits.is_binary());
+    /// # for unit in Units::ALL {
+    /// #     assert!(unit.is_binary() || unit.is_decimal());
+    /// #     assert_eq!(unit.is_binary() && unit.is_decimal(), *unit == Units::Bytes);
+    /// # }
+    /// ```
+    pub const fn is_binary(&self) -> bool {
+        self.base() == 1024 || self.exponent() == 0
+    }
+
+    /// Returns true for powers of 1000, such as `Units::Kilobytes`. `Units::Bytes`
+    /// is both binary and decimal.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert!(Units::Kilobytes.is_decimal());
+    /// assert!(!Units::Kibibytes.is_decimal());
+    /// # assert!(Units::Terabits.is_decimal());
+    /// ```
+    pub const fn is_decimal(&self) -> bool {
+        self.base() == 1000
+    }
+
+    /// Returns true for units counting bits, such as `Units::Megabits`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert!(Units::Megabits.is_bits());
+    /// assert!(!Units::Megabytes.is_bits());
+    /// ```
+    pub const fn is_bits(&self) -> bool {
+        matches!(
+            self,
+            Self::Kilobits
+                | Self::Kibibits
+                | Self::Megabits
+                | Self::Mebibits
+                | Self::Gigabits
+                | Self::Gibibits
+                | Self::Terabits
+                | Self::Tebibits
+        )
     }
 }
 
-impl From<usize> for Size {
-    fn from(rhs: usize) -> Self {
-        Self(rhs)
+impl Units {
+    /// Returns the JEDEC symbol for a binary unit, as used by Windows and a lot of
+    /// firmware documentation, where "KB" means 1024 bytes. Returns `None` for
+    /// decimal units, since JEDEC symbols can't represent them.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::Kibibytes.jedec_symbol(), Some("KB"));
+    /// assert_eq!(Units::Gibibytes.jedec_symbol(), Some("GB"));
+    /// assert_eq!(Units::Bytes.jedec_symbol(), Some("B"));
+    /// assert_eq!(Units::Gigabytes.jedec_symbol(), None);
+    /// ```
+    pub const fn jedec_symbol(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::Bytes => "B",
+            Self::Kibibytes => "KB",
+            Self::Mebibytes => "MB",
+            Self::Gibibytes => "GB",
+            Self::Tebibytes => "TB",
+            Self::Pebibytes => "PB",
+            Self::Exbibytes => "EB",
+            _ => return None,
+        })
     }
 }
 
-impl Into<usize> for Size {
-    fn into(self) -> usize {
-        self.0
+impl Ord for Units {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.bytes().cmp(&other.bytes())
     }
 }
 
-impl TryFrom<isize> for Size {
-    type Error = ();
+impl PartialOrd for Units {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-    /// Will error if x < 0.
-    fn try_from(rhs: isize) -> Result<Self, ()> {
-        Ok(Self(rhs.try_into().map_err(|_| ())?))
+impl Units {
+    /// Returns the symbol for this unit, such as "KiB". This is what `Display` prints.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::Kibibytes.symbol(), "KiB");
+    /// # for unit in Units::ALL {
+    /// #     assert_eq!(unit.symbol(), unit.to_string());
+    /// # }
+    /// ```
+    pub const fn symbol(&self) -> &'static str {
+        match self {
+            Self::Bytes => "B",
+            Self::Kilobytes => "kB",
+            Self::Kibibytes => "KiB",
+            Self::Megabytes => "MB",
+            Self::Mebibytes => "MiB",
+            Self::Gigabytes => "GB",
+            Self::Gibibytes => "GiB",
+            Self::Terabytes => "TB",
+            Self::Tebibytes => "TiB",
+            Self::Petabytes => "PB",
+            Self::Pebibytes => "PiB",
+            Self::Exabytes => "EB",
+            Self::Exbibytes => "EiB",
+            Self::Kilobits => "kbit",
+            Self::Kibibits => "Kibit",
+            Self::Megabits => "Mbit",
+            Self::Mebibits => "Mibit",
+            Self::Gigabits => "Gbit",
+            Self::Gibibits => "Gibit",
+            Self::Terabits => "Tbit",
+            Self::Tebibits => "Tibit",
+        }
+    }
+
+    /// Returns the lowercase name of one of this unit, such as "kibibyte".
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::Kibibytes.name_singular(), "kibibyte");
+    /// assert_eq!(Units::Megabits.name_singular(), "megabit");
+    /// ```
+    pub const fn name_singular(&self) -> &'static str {
+        self.names()[0]
+    }
+
+    /// Returns the lowercase name of several of this unit, such as "kibibytes".
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::Kibibytes.name_plural(), "kibibytes");
+    /// assert_eq!(Units::Bytes.name_plural(), "bytes");
+    /// # for unit in Units::ALL {
+    /// #     assert_eq!(unit.name_plural(), format!("{}s", unit.name_singular()));
+    /// # }
+    /// ```
+    pub const fn name_plural(&self) -> &'static str {
+        self.names()[1]
+    }
+
+    const fn names(&self) -> [&'static str; 2] {
+        match self {
+            Self::Bytes => ["byte", "bytes"],
+            Self::Kilobytes => ["kilobyte", "kilobytes"],
+            Self::Kibibytes => ["kibibyte", "kibibytes"],
+            Self::Megabytes => ["megabyte", "megabytes"],
+            Self::Mebibytes => ["mebibyte", "mebibytes"],
+            Self::Gigabytes => ["gigabyte", "gigabytes"],
+            Self::Gibibytes => ["gibibyte", "gibibytes"],
+            Self::Terabytes => ["terabyte", "terabytes"],
+            Self::Tebibytes => ["tebibyte", "tebibytes"],
+            Self::Petabytes => ["petabyte", "petabytes"],
+            Self::Pebibytes => ["pebibyte", "pebibytes"],
+            Self::Exabytes => ["exabyte", "exabytes"],
+            Self::Exbibytes => ["exbibyte", "exbibytes"],
+            Self::Kilobits => ["kilobit", "kilobits"],
+            Self::Kibibits => ["kibibit", "kibibits"],
+            Self::Megabits => ["megabit", "megabits"],
+            Self::Mebibits => ["mebibit", "mebibits"],
+            Self::Gigabits => ["gigabit", "gigabits"],
+            Self::Gibibits => ["gibibit", "gibibits"],
+            Self::Terabits => ["terabit", "terabits"],
+            Self::Tebibits => ["tebibit", "tebibits"],
+        }
+    }
+}
+
+/// Prints the unit's symbol, or with the alternate flag (`{:#}`), its plural name.
+///
+/// ```rust
+/// # use repr_size::*;
+/// assert_eq!(format!("{}", Units::Mebibytes), "MiB");
+/// assert_eq!(format!("{:#}", Units::Mebibytes), "mebibytes");
+/// # for unit in Units::ALL {
+/// #     assert_eq!(format!("{}", unit), unit.symbol());
+/// #     assert_eq!(format!("{:#}", unit), unit.name_plural());
+/// #     assert_eq!(Units::parse_lenient(&format!("{:#}", unit)), Ok(*unit));
+/// # }
+/// assert_eq!(format!("[{:>5}]", Units::Kibibytes), "[  KiB]");
+/// assert_eq!(format!("[{:<5}]", Units::Bytes), "[B    ]");
+/// # assert_eq!(format!("[{:-^#11}]", Units::Kibibytes), "[-kibibytes-]");
+/// ```
+impl Display for Units {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = if f.alternate() {
+            self.name_plural()
+        } else {
+            self.symbol()
+        };
+        format::pad(f, format_args!("{}", name))
+    }
+}
+
+/// Represents an amount of bytes.
+/// Create these by using `Size::from(u64)`, `Size::new(u64)` or `Size::from_units(usize, Units)`
+///
+/// The byte count is stored as a `u64` regardless of the target's pointer width,
+/// so sizes above 4 GiB work on 32-bit platforms too.
+///
+/// ```rust
+/// # use repr_size::*;
+/// let disk = Size::from(6_000_000_000u64);
+///
+/// assert_eq!(u64::from(disk), 6_000_000_000);
+/// assert_eq!(disk.get_units(), Units::Gigabytes);
+/// assert_eq!(disk.to_decimal_string(), "6.0 GB");
+/// # assert_eq!(Size::from(1u64 << 32).get_binary_unit(), Units::Gibibytes);
+/// # assert_eq!(Size::from(1u64 << 32).to_binary_string(), "4.0 GiB");
+/// # assert_eq!(Size::from_units(5, Units::Gibibytes), Size::from(5u64 << 30));
+/// # assert_eq!(Size::from(5u64 << 30).repr(Units::Mebibytes), "5120.0 MiB");
+/// ```
+///
+/// With the `serde` feature, sizes are (de)serialized as a bare `u64`, so a value
+/// written on a 64-bit host reads back the same on a 32-bit one. Negative numbers
+/// and numbers past `u64::MAX` fail with a message saying so. The `serde_string`
+/// module writes sizes as strings such as "25 MiB" instead.
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// # use repr_size::*;
+/// let json = serde_json::to_string(&Size::from(6_000_000_000u64)).unwrap();
+/// assert_eq!(json, "6000000000");
+/// assert_eq!(serde_json::from_str::<Size>(&json).unwrap(), Size::from(6_000_000_000u64));
+///
+/// let err = serde_json::from_str::<Size>("-5").unwrap_err();
+/// assert_eq!(err.to_string(), "byte sizes cannot be negative, got -5 at line 1 column 2");
+/// # use serde::de::value::{Error, I128Deserializer, I64Deserializer, U128Deserializer, U64Deserializer};
+/// # use serde::Deserialize;
+/// # for bytes in [0, u32::MAX as u64, u32::MAX as u64 + 1, 1 << 40, u64::MAX] {
+/// #     let json = serde_json::to_string(&Size::from(bytes)).unwrap();
+/// #     assert_eq!(json, bytes.to_string());
+/// #     assert_eq!(serde_json::from_str::<Size>(&json).unwrap(), Size::from(bytes));
+/// #     assert_eq!(serde_json::from_value::<Size>(serde_json::json!(bytes)).unwrap(), Size::from(bytes));
+/// #     assert_eq!(Size::deserialize(U64Deserializer::<Error>::new(bytes)), Ok(Size::from(bytes)));
+/// #     assert_eq!(Size::deserialize(U128Deserializer::<Error>::new(bytes.into())), Ok(Size::from(bytes)));
+/// #     assert_eq!(Size::deserialize(I128Deserializer::<Error>::new(bytes.into())), Ok(Size::from(bytes)));
+/// # }
+/// # assert_eq!(Size::deserialize(I64Deserializer::<Error>::new(i64::MAX)), Ok(Size::from(i64::MAX as u64)));
+/// # let err = |result: Result<Size, Error>| result.unwrap_err().to_string();
+/// # assert_eq!(err(Size::deserialize(I64Deserializer::new(-1))), "byte sizes cannot be negative, got -1");
+/// # assert_eq!(err(Size::deserialize(I128Deserializer::new(i128::MIN))), format!("byte sizes cannot be negative, got {}", i128::MIN));
+/// # assert_eq!(
+/// #     err(Size::deserialize(U128Deserializer::new(u64::MAX as u128 + 1))),
+/// #     "18446744073709551616 bytes is larger than the maximum size of 18446744073709551615 bytes",
+/// # );
+/// # assert_eq!(
+/// #     err(Size::deserialize(U128Deserializer::new(u128::MAX))),
+/// #     format!("{} bytes is larger than the maximum size of 18446744073709551615 bytes", u128::MAX),
+/// # );
+/// # assert_eq!(
+/// #     err(Size::deserialize(I128Deserializer::new(u64::MAX as i128 + 1))),
+/// #     "18446744073709551616 bytes is larger than the maximum size of 18446744073709551615 bytes",
+/// # );
+/// # assert!(serde_json::from_str::<Size>("18446744073709551616").unwrap_err().to_string()
+/// #     .starts_with("invalid type: floating point `1.8446744073709552e+19`, expected a number of bytes from 0 to 18446744073709551615"));
+/// # assert!(serde_json::from_str::<Size>("\"1 kB\"").unwrap_err().to_string()
+/// #     .starts_with("invalid type: string \"1 kB\", expected a number of bytes"));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Size(u64);
+
+/// Prints the size with decimal units, or binary ones with the `default-binary`
+/// feature. A precision flag sets the number of decimals, which defaults to one.
+/// Sizes under a kilobyte are shown in whole bytes, with no decimals.
+///
+/// The size is written straight to the formatter, so `to_string` allocates only
+/// the `String` it returns, and writing into a buffer with room allocates nothing.
+///
+/// ```rust
+/// # use repr_size::*;
+/// # if cfg!(feature = "default-binary") { return; }
+/// let size = Size::from(54_222u64);
+///
+/// assert_eq!(format!("{}", size), "54.2 kB");
+/// assert_eq!(format!("{:.0}", size), "54 kB");
+/// assert_eq!(format!("{:.3}", size), "54.222 kB");
+/// # assert_eq!(format!("{:.1}", size), size.to_string());
+/// # assert_eq!(format!("{:.2}", Size::ZERO), "0 B");
+/// # assert_eq!(format!("{:.*}", 2, Size::from(1536u64)), "1.54 kB");
+/// ```
+///
+/// ```rust
+/// # use repr_size::*;
+/// let size = Size::from(54_222u64);
+///
+/// if cfg!(feature = "default-binary") {
+///     assert_eq!(size.to_string(), "53.0 KiB");
+///     assert_eq!(size.to_string(), size.to_binary_string());
+/// } else {
+///     assert_eq!(size.to_string(), "54.2 kB");
+///     assert_eq!(size.to_string(), size.to_decimal_string());
+/// }
+/// # assert_eq!(size.display().to_string(), size.to_string());
+/// # assert_eq!(Size128::from(size).to_string(), size.to_string());
+/// # assert_eq!(SizeDelta::from(size).to_string(), format!("+{}", size));
+/// # assert_eq!(format!("{:#}", size), "53.0 KiB");
+/// # assert_eq!(size.repr(Units::Kilobytes), "54.2 kB");
+/// # assert_eq!(size.humanize(Base::Decimal), "54.2 kB");
+/// # assert_eq!(SizeFormatter::new().format(size), "54.2 kB");
+/// ```
+///
+/// ```rust
+/// # use repr_size::*;
+/// # if cfg!(feature = "default-binary") { return; }
+/// assert_eq!(Size::from(999u64).to_string(), "999 B");
+/// assert_eq!(Size::from(1023u64).to_binary_string(), "1023 B");
+/// # for bytes in [0u64, 1, 999] {
+/// #     assert_eq!(Size::from(bytes).to_string(), format!("{} B", bytes));
+/// #     assert_eq!(Size::from(bytes).to_binary_string(), format!("{} B", bytes));
+/// #     assert_eq!(Size::from(bytes).repr(Units::Bytes), format!("{} B", bytes));
+/// #     assert_eq!(format!("{:.3}", Size::from(bytes)), format!("{} B", bytes));
+/// # }
+/// # assert_eq!(Size::from(1023u64).to_string(), "1.0 kB");
+/// # assert_eq!(Size::from(1023u64).repr_with_precision(Units::Bytes, 4), "1023 B");
+/// # assert_eq!(format!("{:#.2}", Size::from(1023u64)), "1023 B");
+/// # assert_eq!(SizeFormatter::new().precision(3).base(Base::Binary).format(Size::from(1u64)), "1 B");
+/// ```
+///
+/// Width, fill and alignment work like they do for `str`, aligning left by default.
+/// ```rust
+/// # use repr_size::*;
+/// # if cfg!(feature = "default-binary") { return; }
+/// let size = Size::from(54_222u64);
+///
+/// assert_eq!(format!("[{:>10.0}]", size), "[     54 kB]");
+/// assert_eq!(format!("[{:<8}]", size), "[54.2 kB ]");
+/// assert_eq!(format!("[{:^12.2}]", size), "[  54.22 kB  ]");
+/// assert_eq!(format!("[{:*>9}]", size), "[**54.2 kB]");
+/// # assert_eq!(format!("[{:9}]", size), "[54.2 kB  ]");
+/// # assert_eq!(format!("[{:^6}]", Size::ZERO), "[ 0 B  ]");
+/// # assert_eq!(format!("[{:3}]", size), "[54.2 kB]");
+/// ```
+///
+/// The `+` flag writes a leading "+", which is padded along with the rest, for
+/// listing sizes next to `SizeDelta`s.
+/// ```rust
+/// # use repr_size::*;
+/// # if cfg!(feature = "default-binary") { return; }
+/// let size = Size::from(54_222u64);
+///
+/// assert_eq!(format!("{:+}", size), "+54.2 kB");
+/// assert_eq!(format!("[{:>+10}]", size), "[  +54.2 kB]");
+/// assert_eq!(format!("{:+}", Size::ZERO), "+0 B");
+/// # assert_eq!(format!("[{:<+#12.2}]", size), "[+52.95 KiB  ]");
+/// # assert_eq!(format!("[{:+>10}]", size), "[+++54.2 kB]");
+/// # assert_eq!(format!("{:+}", size.display_as(Units::Bytes)), "+54222 B");
+/// # assert_eq!(format!("{:+}", SizeFormatter::new().display(size)), "+54.2 kB");
+/// ```
+///
+/// The alternate flag `{:#}` picks binary units instead, like `Size::to_binary_string`,
+/// and combines with the other flags. With the `default-binary` feature, sizes
+/// print in binary units with or without the flag.
+/// ```rust
+/// # use repr_size::*;
+/// let size = Size::from(54_222u64);
+///
+/// assert_eq!(format!("{:#}", size), "53.0 KiB");
+/// assert_eq!(format!("[{:>#12.2}]", size), "[   52.95 KiB]");
+/// assert_eq!(format!("{:#}", Size::from(1000u64)), "1000 B");
+/// assert_eq!(format!("{:#}", Size::from(1024u64)), "1.0 KiB");
+/// # assert_eq!(Size::from(999u64).to_decimal_string(), "999 B");
+/// # assert_eq!(Size::from(1000u64).to_decimal_string(), "1.0 kB");
+/// # assert_eq!(Size::from(1023u64).to_decimal_string(), "1.0 kB");
+/// # assert_eq!(format!("{:#}", Size::from(1023u64)), "1023 B");
+/// # assert_eq!(Size::from(1_000_000u64).to_decimal_string(), "1.0 MB");
+/// # assert_eq!(format!("{:#}", Size::from(1_000_000u64)), "976.6 KiB");
+/// # assert_eq!(format!("{:#}", Size::from(1_048_575u64)), "1.0 MiB");
+/// # assert_eq!(format!("{:#}", Size::MIB), "1.0 MiB");
+/// # assert_eq!(format!("{:#}", Size::ZERO), "0 B");
+/// # assert_eq!(format!("{:#.0}", Size::MAX), "16 EiB");
+/// # for bytes in [0u64, 1, 999, 1000, 1023, 1024, 1_500_000, u64::MAX] {
+/// #     assert_eq!(format!("{:#}", Size::from(bytes)), Size::from(bytes).to_binary_string());
+/// # }
+/// ```
+impl Display for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let display = format::SizeDisplay {
+            size: *self,
+            base: None,
+            unit: None,
+        };
+        display.fmt(f)
+    }
+}
+
+/// Writes the byte count in scientific notation, followed by " B".
+///
+/// A precision such as `{:.1e}` sets the number of decimals in the mantissa, which
+/// is rounded half to even like `u64` does. The `+` flag, width and alignment
+/// work as for `Display`.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// assert_eq!(format!("{:e}", Size::from(54_222u64)), "5.4222e4 B");
+/// assert_eq!(format!("{:.1e}", Size::from(54_222_000_000u64)), "5.4e10 B");
+/// assert_eq!(format!("{:E}", Size::from(54_222u64)), "5.4222E4 B");
+/// assert_eq!(format!("{:e}", Size::ZERO), "0e0 B");
+/// # assert_eq!(format!("{:.2e}", Size::ZERO), "0.00e0 B");
+/// # assert_eq!(format!("{:e}", Size::from(1u64)), "1e0 B");
+/// # assert_eq!(format!("{:e}", Size::from(7u64)), "7e0 B");
+/// # assert_eq!(format!("{:e}", Size::from(1000u64)), "1e3 B");
+/// # assert_eq!(format!("{:.0e}", Size::from(25u64)), "2e1 B");
+/// # assert_eq!(format!("{:.0e}", Size::from(35u64)), "4e1 B");
+/// # assert_eq!(format!("{:.3e}", Size::from(12u64)), "1.200e1 B");
+/// # assert_eq!(format!("{:e}", Size::MAX), "1.8446744073709551615e19 B");
+/// # assert_eq!(format!("{:.3e}", Size::MAX), "1.845e19 B");
+/// # assert_eq!(format!("{:.2E}", Size::MAX), "1.84E19 B");
+/// # assert_eq!(format!("{:e}", Size::EB), "1e18 B");
+/// # assert_eq!(format!("{:+e}", Size::KB), "+1e3 B");
+/// # assert_eq!(format!("[{:>10.1e}]", Size::from(54_222u64)), "[   5.4e4 B]");
+/// # assert_eq!(format!("[{:<10E}]", Size::KB), "[1E3 B     ]");
+/// ```
+impl std::fmt::LowerExp for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if f.sign_plus() { "+" } else { "" };
+        match f.precision() {
+            Some(decimals) => format::pad(f, format_args!("{}{:.*e} B", sign, decimals, self.0)),
+            None => format::pad(f, format_args!("{}{:e} B", sign, self.0)),
+        }
+    }
+}
+
+/// Writes the byte count in scientific notation with an uppercase "E", like
+/// `LowerExp`.
+impl std::fmt::UpperExp for Size {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if f.sign_plus() { "+" } else { "" };
+        match f.precision() {
+            Some(decimals) => format::pad(f, format_args!("{}{:.*E} B", sign, decimals, self.0)),
+            None => format::pad(f, format_args!("{}{:E} B", sign, self.0)),
+        }
+    }
+}
+
+impl Size {
+    /// An empty size of zero bytes.
+    ///
+    /// ```rust
+    /// # use repr_size::*;
+    /// static NOTHING: Size = Size::ZERO;
+    ///
+    /// assert!(NOTHING.is_zero());
+    /// assert_eq!(NOTHING.to_string(), "0 B");
+    /// # assert_eq!(NOTHING.to_binary_string(), "0 B");
+    /// # assert_eq!(u64::from(NOTHING), 0);
+    /// # assert!(Size::ZERO < Size::from(1u64));
+    /// ```
+    pub const ZERO: Size = Size(0);
+
+    /// The largest size that can be represented.
+    ///
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::MAX.to_decimal_string(), "18.4 EB");
+    /// # assert_eq!(Size::MAX.to_binary_string(), "16.0 EiB");
+    /// # assert_eq!(u64::from(Size::MAX), u64::MAX);
+    /// # #[cfg(target_pointer_width = "64")]
+    /// # assert_eq!(usize::from(Size::MAX), usize::MAX);
+    /// # #[cfg(not(target_pointer_width = "64"))]
+    /// # assert!(usize::try_from(Size::MAX).is_err());
+    /// # assert!(Size::MAX > Size::from(u64::MAX - 1));
+    /// # assert_eq!(Size::MAX.max(Size::ZERO), Size::MAX);
+    /// ```
+    pub const MAX: Size = Size(u64::MAX);
+
+    /// One kilobyte (1000 bytes).
+    pub const KB: Size = Size::from_units(1, Units::Kilobytes);
+    /// One kibibyte (1024 bytes).
+    pub const KIB: Size = Size::from_units(1, Units::Kibibytes);
+    /// One megabyte (1000^2 bytes).
+    pub const MB: Size = Size::from_units(1, Units::Megabytes);
+    /// One mebibyte (1024^2 bytes).
+    pub const MIB: Size = Size::from_units(1, Units::Mebibytes);
+    /// One gigabyte (1000^3 bytes).
+    pub const GB: Size = Size::from_units(1, Units::Gigabytes);
+    /// One gibibyte (1024^3 bytes).
+    pub const GIB: Size = Size::from_units(1, Units::Gibibytes);
+    /// One terabyte (1000^4 bytes).
+    pub const TB: Size = Size::from_units(1, Units::Terabytes);
+    /// One tebibyte (1024^4 bytes).
+    pub const TIB: Size = Size::from_units(1, Units::Tebibytes);
+    /// One petabyte (1000^5 bytes).
+    pub const PB: Size = Size::from_units(1, Units::Petabytes);
+    /// One pebibyte (1024^5 bytes).
+    pub const PIB: Size = Size::from_units(1, Units::Pebibytes);
+    /// One exabyte (1000^6 bytes).
+    pub const EB: Size = Size::from_units(1, Units::Exabytes);
+    /// One exbibyte (1024^6 bytes).
+    pub const EIB: Size = Size::from_units(1, Units::Exbibytes);
+
+    /// Creates a size of the given amount of bytes. Unlike `Size::from`, this
+    /// is usable in `const` contexts.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// const MAX_UPLOAD: Size = Size::from_units(100, Units::Mebibytes);
+    /// const MAX_HEADER: Size = Size::new(8192);
+    ///
+    /// // Size thresholds picked at compile time, largest first.
+    /// static LIMITS: [(Size, &str); 3] = [
+    ///     (Size::GIB, "huge"),
+    ///     (MAX_UPLOAD, "large"),
+    ///     (MAX_HEADER, "small"),
+    /// ];
+    ///
+    /// let label = |size: Size| LIMITS.iter().find(|(limit, _)| size >= *limit).map(|(_, l)| *l);
+    /// assert_eq!(label(Size::from(200u64 << 20)), Some("large"));
+    /// assert_eq!(label(Size::from(10u64)), None);
+    /// # assert_eq!(MAX_HEADER.bytes(), 8192);
+    /// # assert_eq!(Size::KIB.bytes(), 1024);
+    /// # assert_eq!(Size::PB, Size::from(1000u64.pow(5)));
+    /// ```
+    pub const fn new(bytes: u64) -> Size {
+        Self(bytes)
+    }
+
+    /// Returns the amount of bytes this size represents.
+    pub const fn bytes(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns true if this size is zero bytes.
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the amount of bits this size represents. This is a `u128` since
+    /// `Size::MAX` is more bits than fit in a `u64`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// // A download running at 12.5 MB/s is moving 100 megabits every second.
+    /// let per_second = Size::from(12_500_000u64);
+    ///
+    /// assert_eq!(per_second.bits(), 100_000_000);
+    /// assert_eq!(per_second.repr(Units::Megabits), "100.0 Mbit");
+    /// # assert_eq!(Size::MAX.bits(), u64::MAX as u128 * 8);
+    /// ```
+    pub const fn bits(&self) -> u128 {
+        self.0 as u128 * 8
+    }
+
+    /// Creates a size holding the given amount of bits. A partial byte is
+    /// rounded up to a whole one, so 9 bits is 2 bytes.
+    ///
+    /// # Panics
+    /// Panics if the rounded up byte count is larger than `Size::MAX`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// // A 100 Mbit/s link moves 12.5 MB every second.
+    /// let per_second = Size::from_bits(100_000_000);
+    ///
+    /// assert_eq!(per_second, Size::from(12_500_000u64));
+    /// assert_eq!(per_second.to_decimal_string(), "12.5 MB");
+    /// assert_eq!(Size::from_bits(9), Size::from(2u64));
+    /// # assert_eq!(Size::from_bits(0), Size::ZERO);
+    /// # assert_eq!(Size::from_bits(8), Size::from(1u64));
+    /// # assert_eq!(Size::from_bits(Size::MAX.bits()), Size::MAX);
+    /// # assert_eq!(Size::from_bits(Size::MAX.bits() - 7), Size::MAX);
+    /// # assert_eq!(Size::from_units(100, Units::Megabits), per_second);
+    /// ```
+    pub const fn from_bits(bits: u128) -> Size {
+        let bytes = bits.div_ceil(8);
+        assert!(bytes <= u64::MAX as u128, "bit count is larger than Size::MAX");
+        Self(bytes as u64)
+    }
+
+    /// Returns this size as a `NonZeroUsize`, or `None` if it is zero or
+    /// doesn't fit in this target's `usize`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// # use std::num::NonZeroUsize;
+    /// assert_eq!(Size::from(4096u64).non_zero(), NonZeroUsize::new(4096));
+    /// assert_eq!(Size::ZERO.non_zero(), None);
+    /// # assert_eq!(Size::from(usize::MAX).non_zero(), NonZeroUsize::new(usize::MAX));
+    /// ```
+    pub const fn non_zero(&self) -> Option<NonZeroUsize> {
+        if self.0 > usize::MAX as u64 {
+            return None;
+        }
+        NonZeroUsize::new(self.0 as usize)
+    }
+
+    /// Gets the largest decimal unit that can represent this number without all significant digits being in the decimal.
+    ///
+    /// The same as `get_decimal_unit`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let just_over_one_kilobyte = Size::from(1001u64);
+    /// let just_under_one_kilobyte = Size::from(999u64);
+    ///
+    /// assert_eq!(just_over_one_kilobyte.get_units(), Units::Kilobytes);
+    /// assert_eq!(just_under_one_kilobyte.get_units(), Units::Bytes);
+    /// # assert_eq!(Size::from(1000u64).get_units(), Units::Kilobytes);
+    /// # assert_eq!(Size::from(999_999u64).get_units(), Units::Kilobytes);
+    /// # assert_eq!(Size::from(1_000_000u64).get_units(), Units::Megabytes);
+    /// # assert_eq!(Size::from(1000u64.pow(5) - 1).get_units(), Units::Terabytes);
+    /// # assert_eq!(Size::from(1000u64.pow(5)).get_units(), Units::Petabytes);
+    /// # assert_eq!(Size::from(1000u64.pow(6) - 1).get_units(), Units::Petabytes);
+    /// # assert_eq!(Size::from(1000u64.pow(6)).get_units(), Units::Exabytes);
+    /// # assert_eq!(Size::MAX.get_units(), Units::Exabytes);
+    /// # assert_eq!(Size::ZERO.get_units(), Units::Bytes);
+    /// ```
+    pub const fn get_units(&self) -> Units {
+        self.get_units_in(Base::Decimal)
+    }
+
+    /// Gets the largest decimal unit (kB, MB, ...) that can represent this number
+    /// without all significant digits being in the decimal.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(1001u64).get_decimal_unit(), Units::Kilobytes);
+    /// assert_eq!(Size::from(999u64).get_decimal_unit(), Units::Bytes);
+    /// # assert_eq!(Size::MAX.get_decimal_unit(), Units::Exabytes);
+    /// ```
+    pub const fn get_decimal_unit(&self) -> Units {
+        self.get_units_in(Base::Decimal)
+    }
+
+    /// Gets the largest binary unit (KiB, MiB, ...) that can represent this number
+    /// without all significant digits being in the decimal.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let just_over_one_kibibyte = Size::from(1025u64);
+    /// let just_under_one_kibibyte = Size::from(1022u64);
+    ///
+    /// assert_eq!(just_over_one_kibibyte.get_binary_unit(), Units::Kibibytes);
+    /// assert_eq!(just_under_one_kibibyte.get_binary_unit(), Units::Bytes);
+    /// # assert_eq!(Size::from(1024u64).get_binary_unit(), Units::Kibibytes);
+    /// # assert_eq!(Size::from((1u64 << 20) - 1).get_binary_unit(), Units::Kibibytes);
+    /// # assert_eq!(Size::from(1u64 << 20).get_binary_unit(), Units::Mebibytes);
+    /// # assert_eq!(Size::from((1u64 << 50) - 1).get_binary_unit(), Units::Tebibytes);
+    /// # assert_eq!(Size::from(1u64 << 50).get_binary_unit(), Units::Pebibytes);
+    /// # assert_eq!(Size::from((1u64 << 60) - 1).get_binary_unit(), Units::Pebibytes);
+    /// # assert_eq!(Size::from(1u64 << 60).get_binary_unit(), Units::Exbibytes);
+    /// # assert_eq!(Size::MAX.get_binary_unit(), Units::Exbibytes);
+    /// ```
+    pub const fn get_binary_unit(&self) -> Units {
+        self.get_units_in(Base::Binary)
+    }
+
+    /// Gets the largest binary unit that can represent this number without all
+    /// significant digits being in the decimal.
+    ///
+    /// Despite the name, binary units are IEC units, not SI ones.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(1025u64).get_si_units(), Units::Kibibytes);
+    /// # for bytes in [0u64, 1, 1023, 1024, 1_000_000, u64::MAX] {
+    /// #     assert_eq!(Size::from(bytes).get_si_units(), Size::from(bytes).get_binary_unit());
+    /// # }
+    /// ```
+    #[deprecated(note = "SI units are the decimal ones; use `get_binary_unit` for the same result")]
+    pub const fn get_si_units(&self) -> Units {
+        self.get_binary_unit()
+    }
+
+    /// Gets the largest unit of the given base that can represent this number
+    /// without all significant digits being in the decimal.
+    ///
+    /// `get_decimal_unit` and `get_binary_unit` are shorthands for the decimal and binary bases.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let size = Size::from(1010u64);
+    ///
+    /// assert_eq!(size.get_units_in(Base::Decimal), Units::Kilobytes);
+    /// assert_eq!(size.get_units_in(Base::Binary), Units::Bytes);
+    /// assert_eq!(size.humanize(Base::Decimal), size.to_decimal_string());
+    /// # assert_eq!(size.get_units_in(Base::Decimal), size.get_decimal_unit());
+    /// # assert_eq!(size.get_units_in(Base::Binary), size.get_binary_unit());
+    /// # assert_eq!(size.humanize(Base::Binary), size.to_binary_string());
+    /// ```
+    ///
+    /// Every unit starts exactly at its byte count:
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(999u64).get_units_in(Base::Decimal), Units::Bytes);
+    /// assert_eq!(Size::from(1000u64).get_units_in(Base::Decimal), Units::Kilobytes);
+    /// assert_eq!(Size::from(1023u64).get_units_in(Base::Binary), Units::Bytes);
+    /// assert_eq!(Size::from(1024u64).get_units_in(Base::Binary), Units::Kibibytes);
+    /// # assert_eq!(Size::from(999_999_999u64).get_units_in(Base::Decimal), Units::Megabytes);
+    /// # assert_eq!(Size::from(1u64 << 60).get_units_in(Base::Binary), Units::Exbibytes);
+    /// # assert_eq!(Size::MAX.get_units_in(Base::Decimal), Units::Exabytes);
+    /// # assert_eq!(Size::ZERO.get_units_in(Base::Binary), Units::Bytes);
+    /// ```
+    pub const fn get_units_in(&self, base: Base) -> Units {
+        match base {
+            Base::Decimal => decimal_units_for(self.0 as u128),
+            Base::Binary => binary_units_for(self.0 as u128),
+        }
+    }
+
+    /// Returns the size represented as an amount and a decimal unit (kB, MB, ...).
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(54_222u64).to_decimal_string(), "54.2 kB");
+    /// assert_eq!(Size::from(999u64).to_decimal_string(), "999 B");
+    /// # assert_eq!(Size::MIB.to_decimal_string(), "1.0 MB");
+    /// # if !cfg!(feature = "default-binary") {
+    /// #     assert_eq!(Size::from(54_222u64).to_decimal_string(), Size::from(54_222u64).to_string());
+    /// # }
+    /// ```
+    pub fn to_decimal_string(&self) -> String {
+        self.humanize(Base::Decimal)
+    }
+
+    /// Returns the size represented as an amount and a binary unit (KiB, MiB, ...).
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(54_222u64).to_binary_string(), "53.0 KiB");
+    /// assert_eq!(Size::from(1023u64).to_binary_string(), "1023 B");
+    /// # assert_eq!(Size::MIB.to_binary_string(), "1.0 MiB");
+    /// # assert_eq!(Size::MAX.to_binary_string(), "16.0 EiB");
+    /// # assert_eq!(Size::from(54_222u64).to_binary_string(), format!("{:#}", Size::from(54_222u64)));
+    /// ```
+    pub fn to_binary_string(&self) -> String {
+        self.humanize(Base::Binary)
+    }
+
+    /// Returns the size represented as an amount and a binary unit.
+    ///
+    /// Despite the name, binary units are IEC units, not SI ones.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(54_222u64).to_si_string(), "53.0 KiB");
+    /// # for bytes in [0u64, 1, 1023, 1024, 54_222, 1_000_000, u64::MAX] {
+    /// #     assert_eq!(Size::from(bytes).to_si_string(), Size::from(bytes).to_binary_string());
+    /// # }
+    /// ```
+    #[deprecated(note = "SI units are the decimal ones; use `to_binary_string` for the same result")]
+    pub fn to_si_string(&self) -> String {
+        self.to_binary_string()
+    }
+
+    /// Returns the size represented as an amount and a decimal unit like `to_decimal_string`,
+    /// but leaves out the decimal when it rounds to zero.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(2000u64).to_string_trimmed(), "2 kB");
+    /// assert_eq!(Size::from(2300u64).to_string_trimmed(), "2.3 kB");
+    /// # assert_eq!(Size::from(2049u64).to_string_trimmed(), "2 kB");
+    /// # assert_eq!(Size::from(2050u64).to_string_trimmed(), "2.1 kB");
+    /// # assert_eq!(Size::from(1999u64).to_string_trimmed(), "2 kB");
+    /// # assert_eq!(Size::ZERO.to_string_trimmed(), "0 B");
+    /// ```
+    pub fn to_string_trimmed(&self) -> String {
+        SizeFormatter::new().trim_zero_fraction(true).format(*self)
+    }
+
+    /// Returns the size as GNU `ls -lh` and `du -h` write it, such as "53K" or
+    /// "1.1G". See `SizeFormatter::gnu` for the rules.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(54_222u64).to_gnu_string(), "53K");
+    /// assert_eq!(Size::from(1025u64).to_gnu_string(), "1.1K");
+    /// assert_eq!(Size::from(1023u64).to_gnu_string(), "1023");
+    /// # assert_eq!(Size::ZERO.to_gnu_string(), "0");
+    /// ```
+    pub fn to_gnu_string(&self) -> String {
+        SizeFormatter::gnu().format(*self)
+    }
+
+    /// Returns the size as words, spelling out the decimal unit, such as "1.5 megabytes"
+    /// or "1 byte". Use `SizeFormatter::words` for binary units or other precisions.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(1u64).to_words(), "1 byte");
+    /// assert_eq!(Size::from(2u64).to_words(), "2 bytes");
+    /// assert_eq!(Size::from(1_500_000u64).to_words(), "1.5 megabytes");
+    /// assert_eq!(Size::ZERO.to_words(), "0 bytes");
+    /// # assert_eq!(Size::from(1_000_001u64).to_words(), "1 megabyte");
+    /// ```
+    pub fn to_words(&self) -> String {
+        SizeFormatter::words().format(*self)
+    }
+
+    /// Returns the size represented as an amount and the unit picked by `get_units_in`.
+    ///
+    /// `to_decimal_string` and `to_binary_string` are shorthands for the decimal and binary bases.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let size = Size::from(1010u64);
+    ///
+    /// assert_eq!(size.humanize(Base::Decimal), "1.0 kB");
+    /// assert_eq!(size.humanize(Base::Binary), "1010 B");
+    /// # assert_eq!(Size::ZERO.humanize(Base::Binary), "0 B");
+    /// # assert_eq!(Size::from(1024u64).humanize(Base::Binary), "1.0 KiB");
+    ///
+    /// // An f32 would show "1.0 TB" here, since it can't hold the trailing 1000 bytes.
+    /// assert_eq!(Size::from(1_050_000_001_000u64).humanize(Base::Decimal), "1.1 TB");
+    /// # assert_eq!(Size::from(1_049_999_999_999u64).humanize(Base::Decimal), "1.0 TB");
+    /// # assert_eq!(Size::MAX.humanize(Base::Decimal), "18.4 EB");
+    /// # assert_eq!(Size::MAX.humanize(Base::Binary), "16.0 EiB");
+    /// # assert_eq!(format!("{:.19}", Size::MAX.display_as(Units::Exabytes)), "18.4467440737095516150 EB");
+    /// ```
+    pub fn humanize(&self, base: Base) -> String {
+        SizeFormatter::new().base(base).format(*self)
+    }
+
+    /// Returns the size represented as an amount and a binary unit, labelled with
+    /// JEDEC symbols where "KB" means 1024 bytes. `Size::parse_jedec` and
+    /// `SizeParser::decimal_suffix_base(Base::Binary)` read it back.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let one_mebibyte = Size::MIB;
+    ///
+    /// assert_eq!(one_mebibyte.to_binary_string(), "1.0 MiB");
+    /// assert_eq!(one_mebibyte.to_jedec_string(), "1.0 MB");
+    /// assert_eq!(one_mebibyte.to_decimal_string(), "1.0 MB"); // 1.05 MB, rounded
+    /// # assert_eq!(one_mebibyte.repr_with_precision(Units::Megabytes, 2), "1.05 MB");
+    /// # assert_eq!(Size::from(1536u64).to_jedec_string(), "1.5 KB");
+    /// # assert_eq!(Size::from(1_048_575u64).to_jedec_string(), "1.0 MB");
+    /// # assert_eq!(Size::ZERO.to_jedec_string(), "0 B");
+    /// ```
+    pub fn to_jedec_string(&self) -> String {
+        if self.is_zero() {
+            return String::from("0 B");
+        }
+        let mut unit = self.get_binary_unit();
+        let mut amount = format::Scaled::new(self.0 as u128, unit, 1);
+        if let Some(larger) = format::promotion(BINARY_UNITS, unit, &amount) {
+            unit = larger;
+            amount = format::Scaled::new(self.0 as u128, unit, 1);
+        }
+        // Every binary unit has a JEDEC symbol.
+        let symbol = unit.jedec_symbol().unwrap_or_default();
+        format!("{} {}", amount, symbol)
+    }
+
+    /// Parses a command-line argument like `FromStr`, since `std::env::args_os`
+    /// gives arguments as `OsString`s. One that isn't valid Unicode fails with
+    /// `ParseSizeError::NotUnicode` rather than panicking.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// use std::ffi::{OsStr, OsString};
+    ///
+    /// let args = [OsString::from("64 KiB"), OsString::from("1.5 GB")];
+    /// let sizes: Result<Vec<Size>, _> = args.iter().map(|arg| Size::parse_os(arg)).collect();
+    /// assert_eq!(sizes, Ok(vec![Size::KIB * 64, Size::from(1_500_000_000u64)]));
+    ///
+    /// assert_eq!(Size::parse_os(OsStr::new("12 GiBs")), Err(ParseSizeError::UnknownUnit("GiBs".to_owned())));
+    ///
+    /// #[cfg(unix)]
+    /// {
+    ///     use std::os::unix::ffi::OsStrExt;
+    ///
+    ///     let arg = OsStr::from_bytes(b"64 \xffKiB");
+    ///     assert_eq!(Size::parse_os(arg), Err(ParseSizeError::NotUnicode { at: 3 }));
+    /// }
+    /// # #[cfg(unix)]
+    /// # {
+    /// #     use std::os::unix::ffi::OsStrExt;
+    /// #     assert_eq!(Size::parse_os(OsStr::from_bytes(b"\xc3")), Err(ParseSizeError::NotUnicode { at: 0 }));
+    /// #     assert_eq!(Size::parse_os(OsStr::from_bytes(b"1 \xe2\x80\xaf\xe2\x80")), Err(ParseSizeError::NotUnicode { at: 5 }));
+    /// #     assert_eq!(Size::parse_os(OsStr::from_bytes(b"64 KiB")), Ok(Size::KIB * 64));
+    /// # }
+    /// # #[cfg(windows)]
+    /// # {
+    /// #     use std::os::windows::ffi::OsStringExt;
+    /// #     let arg = OsString::from_wide(&[0x36, 0x34, 0xD800]);
+    /// #     assert_eq!(Size::parse_os(&arg), Err(ParseSizeError::NotUnicode { at: 2 }));
+    /// # }
+    /// # assert_eq!(Size::parse_os(OsStr::new("")), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Size::parse_os(OsStr::new("0x10 MiB")), Ok(Size::MIB * 16));
+    /// # assert_eq!(ParseSizeError::NotUnicode { at: 3 }.to_string(), "invalid unicode at byte 3");
+    /// ```
+    pub fn parse_os(arg: &OsStr) -> Result<Size, ParseSizeError> {
+        parse::parse_size_os(arg)
+    }
+
+    /// Parses a size like `FromStr`, but fails with `ParseSizeError::NotWholeBytes`
+    /// rather than rounding when it isn't a whole number of bytes.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::parse_strict("100 Mbit"), Ok(Size::from(12_500_000u64)));
+    /// assert_eq!(Size::parse_strict("12 b"), Err(ParseSizeError::NotWholeBytes));
+    /// assert_eq!(Size::parse_strict("1.5 B"), Err(ParseSizeError::NotWholeBytes));
+    /// # assert_eq!(Size::parse_strict("16 bit"), Ok(Size::from(2u64)));
+    /// # assert_eq!(Size::parse_strict("0.001 kbit"), Err(ParseSizeError::NotWholeBytes));
+    /// # assert_eq!(Size::parse_strict("0.008 kbit"), Ok(Size::from(1u64)));
+    /// # assert_eq!(Size::parse_strict("1.1 KiB"), Err(ParseSizeError::NotWholeBytes));
+    /// # assert_eq!(Size::parse_strict("1.5 KiB"), Ok(Size::from(1536u64)));
+    /// # assert_eq!(Size::parse_strict("54.2 kB"), Ok(Size::from(54_200u64)));
+    /// # assert_eq!(Size::parse_strict("0.5 Kibit"), Ok(Size::from(64u64)));
+    /// # assert_eq!(Size::parse_strict("x"), Err(ParseSizeError::InvalidNumber { at: 0 }));
+    /// # assert_eq!(ParseSizeError::NotWholeBytes.to_string(), "size is not a whole number of bytes");
+    /// ```
+    pub fn parse_strict(s: &str) -> Result<Size, ParseSizeError> {
+        parse::parse_size_strict(s)
+    }
+
+    /// Parses a size written by hand, such as "2 Kilobytes", "512 MIB" or "1 gig".
+    ///
+    /// Units are read like `Units::parse_lenient` does: in any case, as a singular
+    /// or plural name, or as one of the aliases "k", "meg", "megs", "gig" and
+    /// "gigs", which are decimal. Symbols only ignore the case of their prefix, so
+    /// "kb" is still kilobits and "KB" kilobytes. `FromStr` stays strict about case.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::parse_lenient("2 Kilobytes"), Ok(Size::from(2000u64)));
+    /// assert_eq!(Size::parse_lenient("512 MIB"), Ok(Size::from(512u64 * 1024 * 1024)));
+    /// assert_eq!(Size::parse_lenient("100 mb"), Ok(Size::from(12_500_000u64)));
+    /// assert_eq!(Size::parse_lenient("1 gig"), Ok(Size::GB));
+    /// assert_eq!(Size::parse_lenient("3 GIGS"), Ok(Size::from(3_000_000_000u64)));
+    /// assert!("2 Kilobytes".parse::<Size>().is_err());
+    /// # assert_eq!(Size::parse_lenient("1 kilobyte"), Ok(Size::KB));
+    /// # assert_eq!(Size::parse_lenient("1.5 MEGABYTES"), Ok(Size::from(1_500_000u64)));
+    /// # assert_eq!(Size::parse_lenient("10k"), Ok(Size::from(10_000u64)));
+    /// # assert_eq!(Size::parse_lenient("4 megs"), Ok(Size::from(4_000_000u64)));
+    /// # assert_eq!(Size::parse_lenient("100 Megabits"), Ok(Size::from(12_500_000u64)));
+    /// # assert_eq!(Size::parse_lenient("8Mb"), Ok(Size::MB));
+    /// # assert_eq!(Size::parse_lenient("8 mb"), Ok(Size::MB));
+    /// # assert_eq!(Size::parse_lenient("8 MB"), Ok(Size::from(8_000_000u64)));
+    /// # assert_eq!(Size::parse_lenient("1 Kb"), Ok(Size::from(125u64)));
+    /// # assert_eq!(Size::parse_lenient("1 kb"), Ok(Size::from(125u64)));
+    /// # assert_eq!(Size::parse_lenient("1 kB"), Ok(Size::KB));
+    /// # assert_eq!(Size::parse_lenient("1 KB"), Ok(Size::KB));
+    /// # assert_eq!(Size::parse_lenient("1 KBIT"), Ok(Size::from(125u64)));
+    /// # assert_eq!(Size::parse_lenient("1 kIb"), Ok(Size::from(128u64)));
+    /// # assert_eq!(Size::parse_lenient("1 kIB"), Ok(Size::KIB));
+    /// # assert_eq!(Size::parse_lenient("16 b"), Ok(Size::from(2u64)));
+    /// # assert_eq!(Size::parse_lenient("2,000 bytes"), Ok(Size::from(2000u64)));
+    /// # assert_eq!(Size::parse_lenient("1 Exbibyte"), Ok(Size::EIB));
+    /// # assert_eq!(Size::parse_lenient("1 gigz").unwrap_err().to_string(), "unknown unit `gigz`, did you mean `GB`?");
+    /// # assert_eq!(Size::parse_lenient("1 gigz").unwrap_err().suggestion(), Some(Units::Gigabytes));
+    /// # assert_eq!(Size::parse_lenient("1 parsec"), Err(ParseSizeError::UnknownUnit("parsec".to_owned())));
+    /// # assert_eq!(Size::parse_lenient(""), Err(ParseSizeError::Empty));
+    /// # for unit in Units::ALL.iter().filter(|unit| !unit.is_bits()) {
+    /// #     assert_eq!(Size::parse_lenient(&format!("3 {}", unit.name_plural().to_uppercase())), Ok(Size::from_units(3, *unit)));
+    /// #     assert_eq!(SizeParser::new().lenient_units(true).parse(&format!("1 {}", unit.name_singular())), Ok(Size::from_units(1, *unit)));
+    /// # }
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Size, ParseSizeError> {
+        parse::parse_size_lenient(s)
+    }
+
+    /// Parses a sum of sizes, such as "1 GiB 512 MiB 7 B" or "1GiB+512MiB", into
+    /// their exact total. It reads what `to_breakdown_string` writes.
+    ///
+    /// Terms are separated by whitespace, a "+", or both, and each is parsed like
+    /// `FromStr`. They can be in any order and repeat units, and a term without a
+    /// unit is in bytes. `SizeParser::parse_sum` takes the same options as `SizeParser::parse`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let size = Size::GIB + Size::from_units(512, Units::Mebibytes) + Size::from(7u64);
+    ///
+    /// assert_eq!(Size::parse_sum("1 GiB 512 MiB 7 B"), Ok(size));
+    /// assert_eq!(Size::parse_sum("1GiB+512MiB+7B"), Ok(size));
+    /// assert_eq!(Size::parse_sum("7 B + 256 MiB 1 GiB 256MiB"), Ok(size));
+    /// assert_eq!(Size::parse_sum(""), Err(ParseSizeError::Empty));
+    /// assert_eq!(Size::parse_sum("16 EiB 1 B"), Err(ParseSizeError::Overflow));
+    /// # assert_eq!(Size::parse_sum("1 GB"), Ok(Size::GB));
+    /// # assert_eq!(Size::parse_sum("1 GB 512"), Ok(Size::from(1_000_000_512u64)));
+    /// # assert_eq!(Size::parse_sum("1 GB 1 2"), Ok(Size::from(1_000_000_003u64)));
+    /// # assert_eq!(Size::parse_sum("  1.5 kB\t+\n2 kB  "), Ok(Size::from(3500u64)));
+    /// # assert_eq!(Size::parse_sum("1,000 kB 1 MB"), Ok(Size::from(2_000_000u64)));
+    /// # assert_eq!(Size::parse_sum("1\u{202F}MB 1\u{202F}kB"), Ok(Size::from(1_001_000u64)));
+    /// # assert_eq!(Size::parse_sum("8 Mbit 1 MB"), Ok(Size::from(2_000_000u64)));
+    /// # assert_eq!(Size::parse_sum("15 EiB 1023 PiB 1023 TiB 1023 GiB 1023 MiB 1023 KiB 1024 B"), Err(ParseSizeError::Overflow));
+    /// # assert_eq!(Size::parse_sum(" + "), Err(ParseSizeError::InvalidNumber { at: 1 }));
+    /// # assert_eq!(Size::parse_sum("+1 GB"), Err(ParseSizeError::InvalidNumber { at: 0 }));
+    /// # assert_eq!(Size::parse_sum("1 GB +"), Err(ParseSizeError::InvalidNumber { at: 6 }));
+    /// # assert_eq!(Size::parse_sum("1 GB ++ 1 MB"), Err(ParseSizeError::InvalidNumber { at: 6 }));
+    /// # assert_eq!(Size::parse_sum("1 GB -1 MB"), Err(ParseSizeError::NegativeNotAllowed));
+    /// # assert_eq!(Size::parse_sum("1 GB 1.2.3 MB"), Err(ParseSizeError::InvalidNumber { at: 8 }));
+    /// # assert_eq!(Size::parse_sum("1 GB MB"), Err(ParseSizeError::InvalidNumber { at: 5 }));
+    /// # assert_eq!(Size::parse_sum("1 GB 2 XB"), Err(ParseSizeError::UnknownUnit("XB".to_owned())));
+    /// # assert_eq!(Size::parse_sum("1 gig 2 megs"), Err(ParseSizeError::UnknownUnit("gig".to_owned())));
+    /// # assert_eq!(SizeParser::new().allow_aliases(true).parse_sum("1 gig 2 megs"), Ok(Size::from(1_002_000_000u64)));
+    /// ```
+    ///
+    /// A breakdown parses back to the size it came from:
+    /// ```rust
+    /// # use repr_size::*;
+    /// let size = Size::from(5_000_000_123u64);
+    /// for base in [Base::Decimal, Base::Binary] {
+    ///     assert_eq!(Size::parse_sum(&size.to_breakdown_string(base)), Ok(size));
+    /// }
+    /// # for size in [Size::ZERO, Size::from(1u64), Size::MAX] {
+    /// #     for base in [Base::Decimal, Base::Binary] {
+    /// #         assert_eq!(Size::parse_sum(&size.to_breakdown_string(base)), Ok(size));
+    /// #     }
+    /// # }
+    /// ```
+    pub fn parse_sum(s: &str) -> Result<Size, ParseSizeError> {
+        SizeParser::new().parse_sum(s)
+    }
+
+    /// Parses a size, reading a number without a unit as a number of `default`
+    /// units, the way systemd and nginx directives treat a bare number.
+    ///
+    /// A unit in the string still wins. A fraction is scaled by the default unit
+    /// and rounded to the nearest byte, or up to a whole byte for bit units, like
+    /// any other size.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::parse_with_default_unit("512", Units::Mebibytes), Ok(Size::MIB * 512));
+    /// assert_eq!(Size::parse_with_default_unit("1.5", Units::Kibibytes), Ok(Size::from(1536u64)));
+    /// assert_eq!(Size::parse_with_default_unit("512 kB", Units::Mebibytes), Ok(Size::from(512_000u64)));
+    /// assert_eq!(Size::parse_with_default_unit("", Units::Mebibytes), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Size::parse_with_default_unit("512 B", Units::Mebibytes), Ok(Size::from(512u64)));
+    /// # assert_eq!(Size::parse_with_default_unit("0.0001", Units::Kilobytes), Ok(Size::ZERO));
+    /// # assert_eq!(Size::parse_with_default_unit("0.0005", Units::Kilobytes), Ok(Size::from(1u64)));
+    /// # assert_eq!(Size::parse_with_default_unit("12", Units::Kilobits), Ok(Size::from(1500u64)));
+    /// # assert_eq!(Size::parse_with_default_unit("1,024,000", Units::Kilobytes), Ok(Size::from(1_024_000_000u64)));
+    /// # assert_eq!(Size::parse_with_default_unit("0x10", Units::Mebibytes), Ok(Size::MIB * 16));
+    /// # assert_eq!(Size::parse_with_default_unit(".5", Units::Gigabytes), Ok(Size::from(500_000_000u64)));
+    /// # assert_eq!(Size::parse_with_default_unit("17", Units::Exbibytes), Err(ParseSizeError::Overflow));
+    /// # assert_eq!(Size::parse_with_default_unit("   ", Units::Kilobytes), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Size::parse_with_default_unit("-1", Units::Kilobytes), Err(ParseSizeError::NegativeNotAllowed));
+    /// # assert_eq!(Size::parse_with_default_unit("1 XB", Units::Kilobytes), Err(ParseSizeError::UnknownUnit("XB".to_owned())));
+    /// # assert_eq!(Size::parse_with_default_unit("7", Units::Bytes), "7".parse::<Size>());
+    /// # assert_eq!(SizeParser::new().default_unit(Units::Kilobytes).parse_sum("1 MB 24"), Ok(Size::from(1_024_000u64)));
+    /// # assert_eq!(SizeParser::strict().default_unit(Units::Kibibytes).exact(true).parse("0.1"), Err(ParseSizeError::NotWholeBytes));
+    /// ```
+    pub fn parse_with_default_unit(s: &str, default: Units) -> Result<Size, ParseSizeError> {
+        SizeParser::new().default_unit(default).parse(s)
+    }
+
+    /// Parses the longest size at the start of `input` and returns it with the
+    /// rest of `input`, for tokenizers that don't know where a size ends. Nothing
+    /// is allocated.
+    ///
+    /// The size is read like `FromStr` reads it, stopping at the first character
+    /// that can't continue it. A unit is the whole run of letters after the number,
+    /// so "1 GiBs" is one byte followed by " GiBs". Leading whitespace is skipped,
+    /// and whitespace after the number is left in the rest unless a unit follows it. Fails with
+    /// `ParseSizeError::Empty` if `input` doesn't start with a number.
+    /// `SizeParser::parse_prefix` takes the same options as `SizeParser::parse`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::parse_prefix("2GiB && age<7d"), Ok((Size::GIB * 2, " && age<7d")));
+    /// assert_eq!(Size::parse_prefix("1.5 MB)"), Ok((Size::from(1_500_000u64), ")")));
+    /// assert_eq!(Size::parse_prefix("512 bytes"), Ok((Size::from(512u64), " bytes")));
+    /// assert_eq!(Size::parse_prefix("64 KiB"), Ok((Size::from(65_536u64), "")));
+    /// assert_eq!(Size::parse_prefix("age<7d"), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Size::parse_prefix(""), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Size::parse_prefix("  "), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Size::parse_prefix(". kB"), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Size::parse_prefix("KiB"), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Size::parse_prefix("-1 kB"), Err(ParseSizeError::NegativeNotAllowed));
+    /// # assert_eq!(Size::parse_prefix("17 EiB"), Err(ParseSizeError::Overflow));
+    /// # assert_eq!(Size::parse_prefix("  7"), Ok((Size::from(7u64), "")));
+    /// # assert_eq!(Size::parse_prefix("7 "), Ok((Size::from(7u64), " ")));
+    /// # assert_eq!(Size::parse_prefix("7>=8"), Ok((Size::from(7u64), ">=8")));
+    /// # assert_eq!(Size::parse_prefix("1 GiB 512 MiB"), Ok((Size::GIB, " 512 MiB")));
+    /// # assert_eq!(Size::parse_prefix("1 GiBs"), Ok((Size::from(1u64), " GiBs")));
+    /// # assert_eq!(Size::parse_prefix("1 GiB2"), Ok((Size::GIB, "2")));
+    /// # assert_eq!(Size::parse_prefix("1GiB_x"), Ok((Size::GIB, "_x")));
+    /// # assert_eq!(Size::parse_prefix("100 Mbit/s"), Ok((Size::from(12_500_000u64), "/s")));
+    /// # assert_eq!(Size::parse_prefix("16b,"), Ok((Size::from(2u64), ",")));
+    /// # assert_eq!(Size::parse_prefix("1.2.3 kB"), Ok((Size::from(1u64), ".3 kB")));
+    /// # assert_eq!(Size::parse_prefix("1,048,576 B;"), Ok((Size::MIB, ";")));
+    /// # assert_eq!(Size::parse_prefix("1,5 MB"), Ok((Size::from(1u64), ",5 MB")));
+    /// # assert_eq!(Size::parse_prefix("max(1,2)"), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Size::parse_prefix("0x10 MiB]"), Ok((Size::MIB * 16, "]")));
+    /// # assert_eq!(Size::parse_prefix("0x10.5"), Ok((Size::from(16u64), ".5")));
+    /// # assert_eq!(Size::parse_prefix("0xg"), Ok((Size::ZERO, "xg")));
+    /// # assert_eq!(Size::parse_prefix("0b"), Ok((Size::ZERO, "")));
+    /// # assert_eq!(Size::parse_prefix("0B!"), Ok((Size::ZERO, "!")));
+    /// # assert_eq!(SizeParser::strict().parse_prefix("0x10"), Ok((Size::ZERO, "x10")));
+    /// # assert_eq!(SizeParser::lenient().parse_prefix("2 gigs && x"), Ok((Size::GB * 2, " && x")));
+    /// # assert_eq!(SizeParser::lenient().parse_prefix("2 Kilobytes, 3"), Ok((Size::from(2000u64), ", 3")));
+    /// # assert_eq!(SizeParser::lenient().parse_prefix("1 GiB 512 MiB"), Ok((Size::GIB, " 512 MiB")));
+    /// # assert_eq!(SizeParser::new().decimal_comma(true).parse_prefix("1,5 MB."), Ok((Size::from(1_500_000u64), ".")));
+    /// # assert_eq!(SizeParser::new().default_unit(Units::Kibibytes).parse_prefix("2 && x"), Ok((Size::KIB * 2, " && x")));
+    /// # for s in ["54.2 kB", "1,048,576 B", "0x10 MiB", "100 Mbit", "12 b", "7", "  3 GiB"] {
+    /// #     assert_eq!(Size::parse_prefix(s), s.parse::<Size>().map(|size| (size, "")), "{}", s);
+    /// # }
+    /// ```
+    pub fn parse_prefix(input: &str) -> Result<(Size, &str), ParseSizeError> {
+        SizeParser::new().parse_prefix(input)
+    }
+
+    /// Parses a size, or a percentage of `total` such as "50%", as `split` and
+    /// LVM accept.
+    ///
+    /// A percentage is any number `FromStr` reads followed by "%", and gives
+    /// `total * percent / 100`, rounded to the nearest byte with halves rounding
+    /// up. It can be over 100, but not negative. Anything else is parsed like
+    /// `FromStr`. `SizeParser::parse_or_percent` takes the same options as
+    /// `SizeParser::parse`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let total = Size::GIB * 10;
+    ///
+    /// assert_eq!(Size::parse_or_percent("50%", total), Ok(Size::GIB * 5));
+    /// assert_eq!(Size::parse_or_percent("100%", total), Ok(total));
+    /// assert_eq!(Size::parse_or_percent("0.5%", total), Ok(Size::from(53_687_091u64)));
+    /// assert_eq!(Size::parse_or_percent("250%", total), Ok(Size::GIB * 25));
+    /// assert_eq!(Size::parse_or_percent("500 MiB", total), Ok(Size::MIB * 500));
+    /// assert_eq!(Size::parse_or_percent("-5%", total), Err(ParseSizeError::NegativeNotAllowed));
+    /// # assert_eq!(Size::parse_or_percent("50%", Size::from(3u64)), Ok(Size::from(2u64)));
+    /// # assert_eq!(Size::parse_or_percent("49%", Size::from(3u64)), Ok(Size::from(1u64)));
+    /// # assert_eq!(Size::parse_or_percent("33.3%", Size::from(1000u64)), Ok(Size::from(333u64)));
+    /// # assert_eq!(Size::parse_or_percent("33.35%", Size::from(1000u64)), Ok(Size::from(334u64)));
+    /// # assert_eq!(Size::parse_or_percent("0%", total), Ok(Size::ZERO));
+    /// # assert_eq!(Size::parse_or_percent("50%", Size::ZERO), Ok(Size::ZERO));
+    /// # assert_eq!(Size::parse_or_percent(" 50 % ", total), Ok(Size::GIB * 5));
+    /// # assert_eq!(Size::parse_or_percent("1,000%", Size::KB), Ok(Size::MB / 100));
+    /// # assert_eq!(Size::parse_or_percent("100%", Size::MAX), Ok(Size::MAX));
+    /// # assert_eq!(Size::parse_or_percent("99.9999999999999999999%", Size::MAX), Ok(Size::MAX));
+    /// # assert_eq!(Size::parse_or_percent("101%", Size::MAX), Err(ParseSizeError::Overflow));
+    /// # assert_eq!(Size::parse_or_percent("%", total), Err(ParseSizeError::InvalidNumber { at: 0 }));
+    /// # assert_eq!(Size::parse_or_percent("", total), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Size::parse_or_percent("5 MiB%", total), Err(ParseSizeError::UnknownUnit("MiB%".to_owned())));
+    /// # assert_eq!(Size::parse_or_percent("5 %%", total), Err(ParseSizeError::UnknownUnit("%%".to_owned())));
+    /// # assert_eq!(Size::parse_or_percent("12", total), Ok(Size::from(12u64)));
+    /// # assert_eq!(SizeParser::new().exact(true).parse_or_percent("50%", Size::from(3u64)), Err(ParseSizeError::NotWholeBytes));
+    /// # assert_eq!(SizeParser::lenient().parse_or_percent("1 gig 5 megs", total), Ok(Size::from(1_005_000_000u64)));
+    /// # for s in ["54.2 kB", "1,048,576 B", "0x10 MiB", "100 Mbit", "12 b", "7", "-1 B", "1 XB"] {
+    /// #     assert_eq!(Size::parse_or_percent(s, total), s.parse::<Size>(), "{}", s);
+    /// # }
+    /// ```
+    pub fn parse_or_percent(s: &str, total: Size) -> Result<Size, ParseSizeError> {
+        SizeParser::new().parse_or_percent(s, total)
+    }
+
+    /// Parses a size using JEDEC semantics, where "KB", "MB", "GB" and so on mean
+    /// powers of 1024. IEC symbols like "KiB" are accepted too and mean the same.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::parse_jedec("512KB"), Ok(Size::from(524_288u64)));
+    /// assert_eq!(Size::parse_jedec("1.5 MB"), Ok(Size::from(1_572_864u64)));
+    /// assert_eq!(Size::parse_jedec("512 KiB"), Ok(Size::from(524_288u64)));
+    /// # assert_eq!(Size::parse_jedec("512kB"), Ok(Size::from(524_288u64)));
+    /// # assert_eq!(Size::parse_jedec("1.0 MB"), Ok(Size::MIB));
+    /// # assert_eq!(Size::parse_jedec("100"), Ok(Size::from(100u64)));
+    /// # assert!(Size::parse_jedec("1 XB").is_err());
+    /// ```
+    pub fn parse_jedec(s: &str) -> Result<Size, ParseSizeError> {
+        parse::parse_size_jedec(s)
+    }
+
+    /// Parses a size the way `dd` and `truncate` read their arguments, such as
+    /// "64K", "1.5G" or "10MB".
+    ///
+    /// A single letter K, M, G, T, P or E is a power of 1024, and so is the same
+    /// letter followed by "iB". Followed by just "B" it's a power of 1000. A lowercase
+    /// "k" is accepted too. A bare number, or one followed by "B", is in bytes.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::parse_cli("64K"), Ok(Size::from(65_536u64)));
+    /// assert_eq!(Size::parse_cli("10k"), Ok(Size::from(10_240u64)));
+    /// assert_eq!(Size::parse_cli("1.5G"), Ok(Size::from(1_610_612_736u64)));
+    /// assert_eq!(Size::parse_cli("10MB"), Ok(Size::from(10_000_000u64)));
+    /// assert_eq!(Size::parse_cli("2MiB"), Ok(Size::from(2_097_152u64)));
+    /// assert_eq!(Size::parse_cli("512"), Ok(Size::from(512u64)));
+    /// ```
+    ///
+    /// The suffixes from the `dd` and `truncate` man pages (GNU coreutils 9.1):
+    /// ```rust
+    /// # use repr_size::*;
+    /// // dd: "kB =1000, K =1024, MB =1000*1000, M =1024*1024, xM =M, GB
+    /// // =1000*1000*1000, G =1024*1024*1024, and so on for T, P, E, Z, Y, R, Q.
+    /// // Binary prefixes can be used, too: KiB=K, MiB=M, and so on."
+    /// let dd = [
+    ///     ("1kB", 1000u64),
+    ///     ("1K", 1024),
+    ///     ("1MB", 1000 * 1000),
+    ///     ("1M", 1024 * 1024),
+    ///     ("1GB", 1000 * 1000 * 1000),
+    ///     ("1G", 1024 * 1024 * 1024),
+    ///     ("1TB", 1000u64.pow(4)),
+    ///     ("1T", 1024u64.pow(4)),
+    ///     ("1PB", 1000u64.pow(5)),
+    ///     ("1P", 1024u64.pow(5)),
+    ///     ("1EB", 1000u64.pow(6)),
+    ///     ("1E", 1024u64.pow(6)),
+    ///     ("1KiB", 1024),
+    ///     ("1MiB", 1024 * 1024),
+    /// ];
+    /// // truncate: "The SIZE argument is an integer and optional unit (example: 10K
+    /// // is 10*1024). Units are K,M,G,T,P,E,Z,Y,R,Q (powers of 1024) or KB,MB,...
+    /// // (powers of 1000). Binary prefixes can be used, too: KiB=K, MiB=M, and so on."
+    /// let truncate = [
+    ///     ("10K", 10 * 1024u64),
+    ///     ("1K", 1024),
+    ///     ("1M", 1024u64.pow(2)),
+    ///     ("1G", 1024u64.pow(3)),
+    ///     ("1T", 1024u64.pow(4)),
+    ///     ("1P", 1024u64.pow(5)),
+    ///     ("1E", 1024u64.pow(6)),
+    ///     ("1KB", 1000),
+    ///     ("1MB", 1000u64.pow(2)),
+    ///     ("1GB", 1000u64.pow(3)),
+    ///     ("1KiB", 1024),
+    ///     ("1MiB", 1024u64.pow(2)),
+    /// ];
+    /// for (arg, bytes) in dd.into_iter().chain(truncate) {
+    ///     assert_eq!(Size::parse_cli(arg), Ok(Size::from(bytes)), "{}", arg);
+    /// }
+    /// # for letter in ["K", "M", "G", "T", "P", "E"] {
+    /// #     assert_eq!(Size::parse_cli(&format!("3{}", letter)), Size::parse_cli(&format!("3{}iB", letter)));
+    /// #     assert_eq!(Size::parse_cli(&format!("3 {}", letter)), Size::parse_cli(&format!("3{}", letter)));
+    /// # }
+    /// # assert_eq!(Size::parse_cli("10kB"), Ok(Size::from(10_000u64)));
+    /// # assert_eq!(Size::parse_cli("10kiB"), Ok(Size::from(10_240u64)));
+    /// # assert_eq!(Size::parse_cli("100B"), Ok(Size::from(100u64)));
+    /// # assert_eq!(Size::parse_cli("15E"), Ok(Size::from(15 * 1024u64.pow(6))));
+    /// # assert_eq!(Size::parse_cli("16E"), Err(ParseSizeError::Overflow));
+    /// # assert_eq!(Size::parse_cli("1m"), Err(ParseSizeError::UnknownUnit("m".to_owned())));
+    /// # assert_eq!(Size::parse_cli("1Z"), Err(ParseSizeError::UnknownUnit("Z".to_owned())));
+    /// # assert_eq!(Size::parse_cli("1Mb"), Err(ParseSizeError::UnknownUnit("Mb".to_owned())));
+    /// # assert_eq!(Size::parse_cli("-1K"), Err(ParseSizeError::NegativeNotAllowed));
+    /// # assert_eq!(Size::parse_cli(""), Err(ParseSizeError::Empty));
+    /// ```
+    pub fn parse_cli(s: &str) -> Result<Size, ParseSizeError> {
+        parse::parse_size_cli(s)
+    }
+
+    /// Parses a size with the rules of the Linux kernel's `memparse`, which reads
+    /// command line options like `hugepagesz=2M` or `mem=0x20000000`.
+    ///
+    /// The number is a whole one, in hex with a "0x" prefix, in octal with a leading
+    /// "0", or in decimal otherwise. It can be followed by one of K, M, G, T, P or E in
+    /// either case, which are always powers of 1024. Unlike `FromStr`, there are no
+    /// fractions, no spaces and no decimal units, so "2MB" and "1.5G" are errors.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::parse_memparse("2M"), Ok(Size::from(2_097_152u64)));
+    /// assert_eq!(Size::parse_memparse("64k"), Ok(Size::from(65_536u64)));
+    /// assert_eq!(Size::parse_memparse("0x200000"), Ok(Size::from(2_097_152u64)));
+    /// assert_eq!(Size::parse_memparse("0x10M"), Ok(Size::from(16 * 1_048_576u64)));
+    /// assert_eq!(Size::parse_memparse("2MB"), Err(ParseSizeError::UnknownUnit("MB".to_owned())));
+    /// # assert_eq!(Size::parse_memparse("1G"), Ok(Size::GIB));
+    /// # assert_eq!(Size::parse_memparse("1g"), Ok(Size::GIB));
+    /// # assert_eq!(Size::parse_memparse("3T"), Size::parse_memparse("3t"));
+    /// # assert_eq!(Size::parse_memparse("1p"), Ok(Size::from(1u64 << 50)));
+    /// # assert_eq!(Size::parse_memparse("15E"), Ok(Size::from(15u64 << 60)));
+    /// # assert_eq!(Size::parse_memparse("16E"), Err(ParseSizeError::Overflow));
+    /// # assert_eq!(Size::parse_memparse("4096"), Ok(Size::from(4096u64)));
+    /// # assert_eq!(Size::parse_memparse("0"), Ok(Size::ZERO));
+    /// # assert_eq!(Size::parse_memparse("0X1f"), Ok(Size::from(31u64)));
+    /// # assert_eq!(Size::parse_memparse("0xABCk"), Ok(Size::from(0xABC * 1024u64)));
+    /// # assert_eq!(Size::parse_memparse("0xffffffffffffffff"), Ok(Size::MAX));
+    /// # assert_eq!(Size::parse_memparse("0x10000000000000000"), Err(ParseSizeError::Overflow));
+    /// # assert_eq!(Size::parse_memparse("0x"), Err(ParseSizeError::UnknownUnit("x".to_owned())));
+    /// # assert_eq!(Size::parse_memparse("0xg"), Err(ParseSizeError::UnknownUnit("xg".to_owned())));
+    /// # assert_eq!(Size::parse_memparse("010"), Ok(Size::from(8u64)));
+    /// # assert_eq!(Size::parse_memparse("08"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+    /// # assert_eq!(Size::parse_memparse("1.5G"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+    /// # assert_eq!(Size::parse_memparse("2 M"), Err(ParseSizeError::UnknownUnit(" M".to_owned())));
+    /// # assert_eq!(Size::parse_memparse(" 2M"), Err(ParseSizeError::InvalidNumber { at: 0 }));
+    /// # assert_eq!(Size::parse_memparse("M"), Err(ParseSizeError::InvalidNumber { at: 0 }));
+    /// # assert_eq!(Size::parse_memparse("2MiB"), Err(ParseSizeError::UnknownUnit("MiB".to_owned())));
+    /// # assert_eq!(Size::parse_memparse("-2M"), Err(ParseSizeError::NegativeNotAllowed));
+    /// # assert_eq!(Size::parse_memparse(""), Err(ParseSizeError::Empty));
+    /// ```
+    ///
+    /// A hex number keeps any letter that is a hex digit, as the kernel does, so
+    /// "0x1E" is 30 bytes rather than 1 EiB:
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::parse_memparse("0x1E"), Ok(Size::from(30u64)));
+    /// assert_eq!(Size::parse_memparse("1E"), Ok(Size::from(1u64 << 60)));
+    /// ```
+    pub fn parse_memparse(s: &str) -> Result<Size, ParseSizeError> {
+        parse::parse_memparse(s)
+    }
+
+    /// Returns a string representation of the size using
+    /// the given unit of bytes, with one decimal.
+    ///
+    /// Zero is written as "0 B" whatever the unit. `SizeFormatter::zero_as_bytes`
+    /// keeps the unit instead.
+    /// ```rust
+    /// # use repr_size::*;
+    /// let twenty_two_kb = Size::from(22000u64);
+    ///
+    /// println!("{}", twenty_two_kb.repr(Units::Bytes)); // "22000 B"
+    /// println!("{}", twenty_two_kb.repr(Units::Kibibytes)); // "21.4 KiB"
+    /// println!("{}", twenty_two_kb.repr(Units::Kilobits)); // "176.0 kbit"
+    /// println!("{}", Size::ZERO.repr(Units::Mebibytes)); // "0 B"
+    /// # assert_eq!(twenty_two_kb.repr(Units::Kilobits), "176.0 kbit");
+    /// # assert_eq!(Size::ZERO.repr(Units::Mebibytes), "0 B");
+    /// # assert_eq!(Size::ZERO.repr_exact(), "0 B");
+    /// # assert_eq!(Size::ZERO.to_jedec_string(), "0 B");
+    /// # assert_eq!(Size::ZERO.to_string(), "0 B");
+    /// # assert_eq!(format!("{:.3}", Size::ZERO), "0 B");
+    /// # assert_eq!(Size128::from(0u128).repr(Units::Mebibytes), "0 B");
+    /// # assert_eq!(Size128::from(0u128).to_binary_string(), "0 B");
+    /// # assert_eq!(Size::from(12_000_000u64).repr(Units::Megabits), "96.0 Mbit");
+    /// ```
+    ///
+    /// The number is computed exactly, so large sizes don't lose digits:
+    /// ```rust
+    /// # use repr_size::*;
+    /// let half_gib = Size::from_units(512, Units::Mebibytes);
+    ///
+    /// assert_eq!((Size::TIB + half_gib).repr(Units::Gibibytes), "1024.5 GiB");
+    /// assert_eq!((Size::TIB - half_gib).repr(Units::Gibibytes), "1023.5 GiB");
+    /// assert_eq!(Size::from(16_777_217u64).repr(Units::Bytes), "16777217 B");
+    /// assert_eq!(Size::MAX.repr(Units::Bytes), "18446744073709551615 B");
+    /// # assert_eq!((Size::TIB + half_gib).to_binary_string(), "1.0 TiB");
+    /// # assert_eq!((Size::TIB + half_gib).repr_with_precision(Units::Tebibytes, 4), "1.0005 TiB");
+    /// # assert_eq!((Size::TIB - half_gib).repr_with_precision(Units::Tebibytes, 4), "0.9995 TiB");
+    /// # assert_eq!(Size::MAX.repr(Units::Kilobytes), "18446744073709551.6 kB");
+    /// # assert_eq!((Size::MAX - Size::from(1u64 << 40)).repr(Units::Tebibytes), "16777215.0 TiB");
+    /// # assert_eq!((Size::MAX - Size::from(1u64 << 40)).repr(Units::Mebibytes), "17592184995840.0 MiB");
+    /// ```
+    pub fn repr(&self, unit: Units) -> String {
+        self.repr_with_precision(unit, 1)
+    }
+
+    /// Returns an adapter that writes the size like `to_string`, without allocating,
+    /// for use in `format_args!`, `write!` or logging macros.
+    ///
+    /// The adapter takes its number of decimals, padding and the alternate flag
+    /// `{:#}` for binary units from the format string, like `Display` for `Size`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// use std::fmt::Write;
+    ///
+    /// let size = Size::from(54_222u64);
+    /// let mut line = String::with_capacity(64);
+    ///
+    /// write!(line, "{}", size.display()).unwrap();
+    /// assert_eq!(line, size.to_string());
+    ///
+    /// line.clear();
+    /// write!(line, "[{:>10.2}]", size.display()).unwrap();
+    /// assert_eq!(line, format!("[{:>10.2}]", size)); // "[  54.22 kB]"
+    /// assert_eq!(line.capacity(), 64);
+    /// # line.clear();
+    /// # write!(line, "{}", Size::ZERO.display()).unwrap();
+    /// # assert_eq!(line, "0 B");
+    /// assert_eq!(format!("{:#}", size.display()), "53.0 KiB");
+    /// # assert_eq!(format!("{:#.3}", size.display()), format!("{:#.3}", size));
+    /// ```
+    pub fn display(&self) -> impl Display {
+        format::SizeDisplay {
+            size: *self,
+            base: None,
+            unit: None,
+        }
+    }
+
+    /// Returns an adapter that writes the size like `to_decimal_string`, without
+    /// allocating, whatever the `default-binary` feature says.
+    ///
+    /// The adapter takes its number of decimals and padding from the format string.
+    /// Unlike `display`, it ignores the alternate flag `{:#}` and stays in decimal units.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let size = Size::from(54_222u64);
+    ///
+    /// assert_eq!(size.display_decimal().to_string(), size.to_decimal_string());
+    /// assert_eq!(format!("{:.3}", size.display_decimal()), "54.222 kB");
+    /// # assert_eq!(format!("{:>8}", Size::MB.display_decimal()), "  1.0 MB");
+    /// assert_eq!(format!("{:#}", size.display_decimal()), "54.2 kB");
+    /// # for bytes in [0u64, 1, 999, 1023, 1024, 1_500_000, u64::MAX] {
+    /// #     assert_eq!(Size::from(bytes).display_decimal().to_string(), Size::from(bytes).to_decimal_string());
+    /// # }
+    /// ```
+    pub fn display_decimal(&self) -> impl Display {
+        format::SizeDisplay {
+            size: *self,
+            base: Some(Base::Decimal),
+            unit: None,
+        }
+    }
+
+    /// Returns an adapter that writes the size like `to_binary_string`, without allocating.
+    ///
+    /// Like `display_decimal`, it takes its number of decimals and padding from the
+    /// format string and ignores the alternate flag `{:#}`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// use std::fmt::Write;
+    ///
+    /// let size = Size::from(54_222u64);
+    /// let mut line = String::with_capacity(64);
+    ///
+    /// write!(line, "{}", size.display_binary()).unwrap();
+    /// assert_eq!(line, size.to_binary_string());
+    /// assert_eq!(format!("{:.3}", size.display_binary()), "52.951 KiB");
+    /// # assert_eq!(format!("{:>8}", Size::MIB.display_binary()), " 1.0 MiB");
+    /// # assert_eq!(format!("{:#}", size.display_binary()), "53.0 KiB");
+    /// # for bytes in [0u64, 1, 999, 1023, 1024, 1_500_000, u64::MAX] {
+    /// #     assert_eq!(Size::from(bytes).display_binary().to_string(), Size::from(bytes).to_binary_string());
+    /// #     assert_eq!(Size::from(bytes).display().to_string(), Size::from(bytes).to_string());
+    /// # }
+    /// ```
+    pub fn display_binary(&self) -> impl Display {
+        format::SizeDisplay {
+            size: *self,
+            base: Some(Base::Binary),
+            unit: None,
+        }
+    }
+
+    /// Returns an adapter that writes the size in the given unit like `repr`,
+    /// without allocating.
+    ///
+    /// A precision in the format string sets the number of decimals like
+    /// `repr_with_precision`, and the alternate flag `{:#}` names the unit in full.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// use std::fmt::Write;
+    ///
+    /// let size = Size::from(22_000u64);
+    /// let mut line = String::with_capacity(64);
+    ///
+    /// write!(line, "{}", size.display_as(Units::Kibibytes)).unwrap();
+    /// assert_eq!(line, size.repr(Units::Kibibytes));
+    /// assert_eq!(format!("{:.0}", size.display_as(Units::Kibibytes)), "21 KiB");
+    /// assert_eq!(format!("{:#}", size.display_as(Units::Kibibytes)), "21.5 kibibytes");
+    /// # assert_eq!(format!("{:.3}", size.display_as(Units::Kibibytes)), size.repr_with_precision(Units::Kibibytes, 3));
+    /// # assert_eq!(format!("[{:<10}]", size.display_as(Units::Kilobytes)), "[22.0 kB   ]");
+    /// # assert_eq!(format!("{:#.0}", Size::KB.display_as(Units::Kilobytes)), "1 kilobyte");
+    /// # assert_eq!(format!("{}", Size::ZERO.display_as(Units::Megabytes)), "0 B");
+    /// # assert_eq!(format!("[{:>+5}]", Size::ZERO.display_as(Units::Megabytes)), "[ +0 B]");
+    /// # assert_eq!(format!("{:#.2}", Size::ZERO.display_as(Units::Megabytes)), "0 bytes");
+    /// ```
+    pub fn display_as(&self, unit: Units) -> impl Display {
+        format::SizeDisplay {
+            size: *self,
+            base: None,
+            unit: Some(unit),
+        }
+    }
+
+    /// Returns a string representation of the size using the given unit of bytes
+    /// and number of decimals. With zero decimals, there is no decimal point, and
+    /// `Units::Bytes` never has one.
+    ///
+    /// Like every other formatting method, the number is computed with integer math,
+    /// so it is exact for every size. It is rounded half up at the last decimal.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let forty_megabytes = Size::from_units(40, Units::Megabytes);
+    ///
+    /// assert_eq!(forty_megabytes.repr(Units::Gigabytes), "0.0 GB");
+    /// assert_eq!(forty_megabytes.repr_with_precision(Units::Gigabytes, 3), "0.040 GB");
+    /// assert_eq!(Size::from(1536u64).repr_with_precision(Units::Kibibytes, 0), "2 KiB");
+    /// # assert_eq!(Size::from(1535u64).repr_with_precision(Units::Kibibytes, 0), "1 KiB");
+    /// # assert_eq!(Size::from(1536u64).repr_with_precision(Units::Kibibytes, 2), "1.50 KiB");
+    /// # assert_eq!(Size::from(1999u64).repr_with_precision(Units::Kilobytes, 2), "2.00 kB");
+    /// # assert_eq!(Size::from(1994u64).repr_with_precision(Units::Kilobytes, 2), "1.99 kB");
+    /// # assert_eq!(Size::from(1995u64).repr_with_precision(Units::Kilobytes, 2), "2.00 kB");
+    /// # assert_eq!(Size::from(1u64).repr_with_precision(Units::Kibibytes, 10), "0.0009765625 KiB");
+    /// # assert_eq!(Size::from(1u64).repr_with_precision(Units::Kibibytes, 12), "0.000976562500 KiB");
+    /// # assert_eq!(Size::from(1u64).repr_with_precision(Units::Kibibytes, 9), "0.000976563 KiB");
+    /// # assert_eq!(Size::MAX.repr_with_precision(Units::Bytes, 2), "18446744073709551615 B");
+    /// # assert_eq!(Size::MAX.repr_with_precision(Units::Exbibytes, 3), "16.000 EiB");
+    /// # assert_eq!(Size::MAX.repr_with_precision(Units::Exbibytes, 20), "15.99999999999999999913 EiB");
+    /// # assert_eq!(Size::ZERO.repr_with_precision(Units::Megabytes, 1), "0 B");
+    /// ```
+    pub fn repr_with_precision(&self, unit: Units, decimals: usize) -> String {
+        self.repr_with_rounding(unit, decimals, Rounding::HalfUp)
+    }
+
+    /// Like `repr_with_precision`, but rounds the number to the decimals with the
+    /// given `Rounding` rather than half up.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let size = Size::from(1_536_000u64);
+    ///
+    /// assert_eq!(size.repr_with_rounding(Units::Megabytes, 0, Rounding::HalfUp), "2 MB");
+    /// assert_eq!(size.repr_with_rounding(Units::Megabytes, 0, Rounding::Floor), "1 MB");
+    /// assert_eq!(size.repr_with_rounding(Units::Megabytes, 2, Rounding::HalfEven), "1.54 MB");
+    /// # assert_eq!(size.repr_with_rounding(Units::Megabytes, 2, Rounding::Floor), "1.53 MB");
+    /// ```
+    pub fn repr_with_rounding(&self, unit: Units, decimals: usize, rounding: Rounding) -> String {
+        if self.is_zero() {
+            return String::from("0 B");
+        }
+        let amount = format::Scaled::rounded(self.0 as u128, unit, decimals, rounding);
+        format!("{} {}", amount, unit)
+    }
+
+    /// Returns the largest byte unit of the given base that divides this size
+    /// exactly, or `Units::Bytes` if none does. Zero is a multiple of every unit,
+    /// but reports `Units::Bytes` as well.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from_units(64, Units::Mebibytes).best_exact_unit(Base::Binary), Units::Mebibytes);
+    /// assert_eq!(Size::from_units(1536, Units::Kibibytes).best_exact_unit(Base::Binary), Units::Kibibytes);
+    /// assert_eq!(Size::from_units(1536, Units::Kibibytes).best_exact_unit(Base::Decimal), Units::Bytes);
+    /// # assert_eq!(Size::from_units(3, Units::Exbibytes).best_exact_unit(Base::Binary), Units::Exbibytes);
+    /// # assert_eq!(Size::from(1_000_001u64).best_exact_unit(Base::Decimal), Units::Bytes);
+    /// # assert_eq!(Size::from(2_000_000u64).best_exact_unit(Base::Decimal), Units::Megabytes);
+    /// # assert_eq!(Size::ZERO.best_exact_unit(Base::Decimal), Units::Bytes);
+    /// ```
+    pub const fn best_exact_unit(&self, base: Base) -> Units {
+        if self.is_zero() {
+            return Units::Bytes;
+        }
+        let units = match base {
+            Base::Decimal => DECIMAL_UNITS,
+            Base::Binary => BINARY_UNITS,
+        };
+        let mut i = units.len() - 1;
+        while i > 0 && self.0 % units[i].bytes() != 0 {
+            i -= 1;
+        }
+        units[i]
+    }
+
+    /// Returns the exact byte count followed by " B", such as "54222 B", for
+    /// storing sizes in files.
+    ///
+    /// This never rounds and ignores every formatting option and feature, so the
+    /// string always parses back to the same size with `FromStr`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let size = Size::from(54_222u64);
+    ///
+    /// assert_eq!(size.to_exact_string(), "54222 B");
+    /// assert_eq!(size.to_exact_string().parse::<Size>(), Ok(size));
+    /// assert_eq!(Size::MAX.to_exact_string(), "18446744073709551615 B");
+    /// # assert_eq!(Size::ZERO.to_exact_string(), "0 B");
+    /// # assert_eq!(Size::MIB.to_exact_string(), "1048576 B");
+    /// # for size in [Size::ZERO, Size::from(1u64), Size::KIB, Size::MAX, Size::from(u64::MAX - 1)] {
+    /// #     assert_eq!(size.to_exact_string().parse::<Size>(), Ok(size));
+    /// # }
+    /// ```
+    pub fn to_exact_string(&self) -> String {
+        format!("{} B", self.0)
+    }
+
+    /// Returns the size as a whole number of the largest unit that divides it
+    /// exactly, with no decimal point, such as "64 MiB" or "1536 KiB".
+    ///
+    /// Both bases are tried and the larger unit wins, so the number is as short as
+    /// it can be without losing anything.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from_units(64, Units::Mebibytes).repr_exact(), "64 MiB");
+    /// assert_eq!(Size::from_units(1536, Units::Kibibytes).repr_exact(), "1536 KiB");
+    /// assert_eq!(Size::from(67_108_865u64).repr_exact(), "67108865 B");
+    /// assert_eq!(Size::ZERO.repr_exact(), "0 B");
+    /// # assert_eq!(Size::from_units(64, Units::Megabytes).repr_exact(), "64 MB");
+    /// # assert_eq!(Size::from(1000u64).repr_exact(), "1 kB");
+    /// # assert_eq!(Size::from(1024u64).repr_exact(), "1 KiB");
+    /// # assert_eq!(Size::from(1u64).repr_exact(), "1 B");
+    /// # assert_eq!(Size::MAX.repr_exact(), "18446744073709551615 B");
+    /// ```
+    pub fn repr_exact(&self) -> String {
+        let unit = self
+            .best_exact_unit(Base::Decimal)
+            .max(self.best_exact_unit(Base::Binary));
+        format!("{} {}", self.0 / unit.bytes(), unit)
+    }
+
+    /// Returns the exact size broken down into whole amounts of each unit of the
+    /// given base, largest first, such as "1 GiB 512 MiB 7 B". Units with an
+    /// amount of zero are left out.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let size = Size::GIB + Size::from_units(512, Units::Mebibytes) + Size::from(7u64);
+    ///
+    /// assert_eq!(size.to_breakdown_string(Base::Binary), "1 GiB 512 MiB 7 B");
+    /// assert_eq!(Size::from(1_234_567u64).to_breakdown_string(Base::Decimal), "1 MB 234 kB 567 B");
+    /// assert_eq!(Size::MIB.to_breakdown_string(Base::Binary), "1 MiB");
+    /// assert_eq!(Size::ZERO.to_breakdown_string(Base::Binary), "0 B");
+    /// # assert_eq!(Size::MIB.to_breakdown_string(Base::Decimal), "1 MB 48 kB 576 B");
+    /// # assert_eq!(Size::from(999u64).to_breakdown_string(Base::Decimal), "999 B");
+    /// # assert_eq!(Size::from(1_000_000_001u64).to_breakdown_string(Base::Decimal), "1 GB 1 B");
+    /// # assert_eq!(Size::MAX.to_breakdown_string(Base::Binary), "15 EiB 1023 PiB 1023 TiB 1023 GiB 1023 MiB 1023 KiB 1023 B");
+    /// # assert_eq!(Size::MAX.to_breakdown_string(Base::Decimal), "18 EB 446 PB 744 TB 73 GB 709 MB 551 kB 615 B");
+    /// ```
+    pub fn to_breakdown_string(&self, base: Base) -> String {
+        self.to_breakdown_string_limited(base, usize::MAX)
+    }
+
+    /// Like `to_breakdown_string`, but only writes the `max_components` largest
+    /// amounts and drops the rest, for a summary like "1 GiB 512 MiB". At least one
+    /// amount is always written.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let size = Size::GIB + Size::from_units(512, Units::Mebibytes) + Size::from(7u64);
+    ///
+    /// assert_eq!(size.to_breakdown_string_limited(Base::Binary, 2), "1 GiB 512 MiB");
+    /// assert_eq!(size.to_breakdown_string_limited(Base::Binary, 1), "1 GiB");
+    /// # assert_eq!(size.to_breakdown_string_limited(Base::Binary, 0), "1 GiB");
+    /// # assert_eq!(size.to_breakdown_string_limited(Base::Binary, 3), "1 GiB 512 MiB 7 B");
+    /// # assert_eq!(size.to_breakdown_string_limited(Base::Binary, 10), "1 GiB 512 MiB 7 B");
+    /// # assert_eq!(Size::ZERO.to_breakdown_string_limited(Base::Decimal, 2), "0 B");
+    /// # assert_eq!(Size::from(1_000_000_001u64).to_breakdown_string_limited(Base::Decimal, 2), "1 GB 1 B");
+    /// ```
+    pub fn to_breakdown_string_limited(&self, base: Base, max_components: usize) -> String {
+        if self.is_zero() {
+            return "0 B".to_owned();
+        }
+        let units = match base {
+            Base::Decimal => DECIMAL_UNITS,
+            Base::Binary => BINARY_UNITS,
+        };
+        let mut rest = self.0;
+        let mut parts = Vec::new();
+        for unit in units.iter().rev() {
+            if rest == 0 || parts.len() == max_components.max(1) {
+                break;
+            }
+            let amount = rest / unit.bytes();
+            if amount > 0 {
+                parts.push(format!("{} {}", amount, unit));
+                rest %= unit.bytes();
+            }
+        }
+        parts.join(" ")
+    }
+
+    /// Converts a float byte count into a `Size`, rounding any fractional byte
+    /// half to even (so `2.5` becomes 2 bytes and `3.5` becomes 4).
+    ///
+    /// Errors for NaN, infinities, negative values and values that round past `Size::MAX`.
+    /// Note that floats above 2^53 can't represent every integer, so very large
+    /// inputs are only as precise as the `f64` they arrived in.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::try_from_f64(1.5e9), Ok(Size::from(1_500_000_000u64)));
+    /// assert_eq!(Size::try_from_f64(2.5), Ok(Size::from(2u64)));
+    /// assert_eq!(Size::try_from_f64(3.5), Ok(Size::from(4u64)));
+    /// assert_eq!(Size::try_from_f64(-1.0), Err(FloatSizeError::Negative(-1.0)));
+    /// assert_eq!(Size::try_from_f64(f64::INFINITY), Err(FloatSizeError::Infinite));
+    /// assert_eq!(Size::try_from_f64(1e20), Err(FloatSizeError::TooLarge(1e20)));
+    /// # assert_eq!(Size::try_from_f64(f64::NAN), Err(FloatSizeError::NaN));
+    /// # assert_eq!(Size::try_from_f64(f64::NEG_INFINITY), Err(FloatSizeError::Infinite));
+    /// # assert_eq!(Size::try_from_f64(-0.0), Ok(Size::ZERO));
+    /// # assert_eq!(Size::try_from_f64(u64::MAX as f64), Err(FloatSizeError::TooLarge(u64::MAX as f64)));
+    /// # assert_eq!(Size::try_from_f64(18446744073709549568.0), Ok(Size::from(18446744073709549568u64)));
+    ///
+    /// // 2^53 + 1 isn't representable as an f64, so it arrives as 2^53.
+    /// let two_53 = 9_007_199_254_740_992u64;
+    /// assert_eq!(Size::try_from_f64(two_53 as f64), Ok(Size::from(two_53)));
+    /// assert_eq!(Size::try_from_f64((two_53 + 1) as f64), Ok(Size::from(two_53)));
+    /// # assert_eq!(Size::try_from_f64((two_53 + 2) as f64), Ok(Size::from(two_53 + 2)));
+    /// # assert_eq!(Size::try_from_f64(two_53 as f64 + 0.5), Ok(Size::from(two_53)));
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn try_from_f64(x: f64) -> Result<Size, FloatSizeError> {
+        if x.is_nan() {
+            return Err(FloatSizeError::NaN);
+        }
+        if x.is_infinite() {
+            return Err(FloatSizeError::Infinite);
+        }
+        if x < 0.0 {
+            return Err(FloatSizeError::Negative(x));
+        }
+        let rounded = x.round_ties_even();
+        // u64::MAX as f64 rounds up to 2^64, which is the first value that doesn't fit.
+        if rounded >= u64::MAX as f64 {
+            return Err(FloatSizeError::TooLarge(x));
+        }
+        Ok(Self(rounded as u64))
+    }
+
+    /// Converts a float byte count into a `Size` on a best-effort basis, rounding
+    /// half to even like `try_from_f64`.
+    ///
+    /// NaN and negative values become `Size::ZERO`, and values too large to
+    /// represent (including infinity) are clamped to `Size::MAX`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from_f64_lossy(2.5), Size::from(2u64));
+    /// assert_eq!(Size::from_f64_lossy(-3.0), Size::ZERO);
+    /// assert_eq!(Size::from_f64_lossy(f64::NAN), Size::ZERO);
+    /// assert_eq!(Size::from_f64_lossy(1e30), Size::MAX);
+    /// # assert_eq!(Size::from_f64_lossy(f64::INFINITY), Size::MAX);
+    /// # assert_eq!(Size::from_f64_lossy(f64::NEG_INFINITY), Size::ZERO);
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn from_f64_lossy(x: f64) -> Size {
+        // Float to int casts saturate at the bounds and map NaN to zero.
+        Self(x.round_ties_even() as u64)
+    }
+
+    /// Returns a Size derived from unit's byte amount times the number given.
+    ///
+    /// # Panics
+    /// Like integer multiplication, this panics in debug builds and wraps in
+    /// release builds if the result is larger than `Size::MAX`. Use
+    /// `checked_from_units` when `x` isn't trusted.
+    pub const fn from_units(x: usize, unit: Units) -> Size {
+        Self(x as u64 * unit.bytes())
+    }
+
+    /// Returns a Size derived from unit's byte amount times the number given,
+    /// or `None` if the result would be larger than `Size::MAX`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::checked_from_units(20, Units::Petabytes), Some(Size::from(20 * 1000u64.pow(5))));
+    /// assert_eq!(Size::checked_from_units(20_000, Units::Petabytes), None);
+    /// # assert_eq!(Size::checked_from_units(u64::MAX as usize, Units::Bytes), Some(Size::MAX));
+    /// # let max_kb = (u64::MAX / 1000) as usize;
+    /// # assert_eq!(Size::checked_from_units(max_kb, Units::Kilobytes), Some(Size::from(max_kb as u64 * 1000)));
+    /// # assert_eq!(Size::checked_from_units(max_kb + 1, Units::Kilobytes), None);
+    /// # assert_eq!(Size::checked_from_units(16384, Units::Pebibytes), None);
+    /// # assert_eq!(Size::checked_from_units(16383, Units::Pebibytes), Some(Size::from(16383u64 << 50)));
+    /// # assert_eq!(Size::checked_from_units(0, Units::Pebibytes), Some(Size::ZERO));
+    /// ```
+    pub const fn checked_from_units(x: usize, unit: Units) -> Option<Size> {
+        match (x as u64).checked_mul(unit.bytes()) {
+            Some(bytes) => Some(Self(bytes)),
+            None => None,
+        }
+    }
+
+    /// Returns a Size derived from unit's byte amount times the number given,
+    /// clamped to `Size::MAX` if the result would be larger. This never panics.
+    ///
+    /// Note that a clamped value displays as the maximum ("18.4 EB"), not as the
+    /// amount that was asked for.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let flag = Size::saturating_from_units(999_999, Units::Petabytes);
+    ///
+    /// assert_eq!(flag, Size::MAX);
+    /// assert_eq!(flag.to_decimal_string(), "18.4 EB");
+    /// # assert_eq!(Size::saturating_from_units(usize::MAX, Units::Pebibytes), Size::MAX);
+    /// # assert_eq!(Size::saturating_from_units(3, Units::Kibibytes), Size::from(3072u64));
+    /// ```
+    pub const fn saturating_from_units(x: usize, unit: Units) -> Size {
+        Self((x as u64).saturating_mul(unit.bytes()))
+    }
+
+    /// Returns a Size from a wide byte count, clamped to `Size::MAX` if it is larger.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::saturating_from_u128(54222), Size::from(54222u64));
+    /// assert_eq!(Size::saturating_from_u128(u128::MAX), Size::MAX);
+    /// # assert_eq!(Size::saturating_from_u128(u64::MAX as u128 + 1), Size::MAX);
+    /// ```
+    pub const fn saturating_from_u128(x: u128) -> Size {
+        if x > u64::MAX as u128 {
+            return Self::MAX;
+        }
+        Self(x as u64)
+    }
+}
+
+/// Position of `unit` in `units`, which must contain it.
+const fn index_in(units: &[Units], unit: Units) -> usize {
+    // Every unit has a distinct byte count, and `==` isn't usable in a const fn.
+    let mut i = 0;
+    while units[i].bytes() != unit.bytes() {
+        i += 1;
+    }
+    i
+}
+
+/// Largest power-of-1000 unit not exceeding `bytes`, shared by `Size` and `Size128`.
+const fn decimal_units_for(bytes: u128) -> Units {
+    // Each unit covers three more decimal digits than the last.
+    let exponent = match bytes.checked_ilog10() {
+        Some(digits) => digits as usize / 3,
+        None => 0,
+    };
+    DECIMAL_UNITS[min_index(exponent, DECIMAL_UNITS.len() - 1)]
+}
+
+/// Largest power-of-1024 unit not exceeding `bytes`, shared by `Size` and `Size128`.
+const fn binary_units_for(bytes: u128) -> Units {
+    // Each unit covers ten more bits than the last.
+    let exponent = match bytes.checked_ilog2() {
+        Some(bits) => bits as usize / 10,
+        None => 0,
+    };
+    BINARY_UNITS[min_index(exponent, BINARY_UNITS.len() - 1)]
+}
+
+/// `usize::min`, which isn't usable in a const fn.
+const fn min_index(a: usize, b: usize) -> usize {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Implements a lossless `From<$int> for Size` and a checked `TryFrom<Size> for $int`
+/// for unsigned integers narrower than the backing `u64`.
+macro_rules! impl_narrow_conversions {
+    ($($int:ty),*) => {$(
+        #[doc = concat!("Converts a `", stringify!($int), "` byte count into a `Size`.")]
+        ///
+        /// ```rust
+        /// # use repr_size::*;
+        #[doc = concat!("let size = Size::from(", stringify!($int), "::MAX);")]
+        #[doc = concat!("assert_eq!(u64::from(size), ", stringify!($int), "::MAX as u64);")]
+        /// ```
+        impl From<$int> for Size {
+            fn from(rhs: $int) -> Self {
+                Self(rhs as u64)
+            }
+        }
+
+        #[doc = concat!("Converts a `Size` back into a `", stringify!($int), "`, failing rather than truncating when it doesn't fit.")]
+        ///
+        /// ```rust
+        /// # use repr_size::*;
+        #[doc = concat!("let fits = Size::from(", stringify!($int), "::MAX);")]
+        #[doc = concat!("assert_eq!(", stringify!($int), "::try_from(fits), Ok(", stringify!($int), "::MAX));")]
+        ///
+        #[doc = concat!("let too_big = Size::from(", stringify!($int), "::MAX as u64 + 1);")]
+        #[doc = concat!("assert!(", stringify!($int), "::try_from(too_big).is_err());")]
+        /// ```
+        impl TryFrom<Size> for $int {
+            type Error = std::num::TryFromIntError;
+
+            fn try_from(rhs: Size) -> Result<Self, Self::Error> {
+                rhs.0.try_into()
+            }
+        }
+    )*};
+}
+
+impl_narrow_conversions!(u8, u16, u32);
+
+impl From<usize> for Size {
+    fn from(rhs: usize) -> Self {
+        Self(rhs as u64)
+    }
+}
+
+/// Converts a `u64` byte count into a `Size`. This is always lossless since `Size` is backed by a `u64`.
+///
+/// ```rust
+/// # use repr_size::*;
+/// let file_len: u64 = 6_000_000_000;
+/// assert_eq!(Size::from(file_len).to_decimal_string(), "6.0 GB");
+/// ```
+impl From<u64> for Size {
+    fn from(rhs: u64) -> Self {
+        Self(rhs)
+    }
+}
+
+impl From<Size> for u64 {
+    fn from(rhs: Size) -> Self {
+        rhs.0
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl From<Size> for usize {
+    fn from(rhs: Size) -> Self {
+        rhs.0 as usize
+    }
+}
+
+#[cfg(not(target_pointer_width = "64"))]
+impl TryFrom<Size> for usize {
+    type Error = std::num::TryFromIntError;
+
+    /// Will error if the size does not fit in this target's `usize`.
+    fn try_from(rhs: Size) -> Result<Self, Self::Error> {
+        rhs.0.try_into()
+    }
+}
+
+impl Size {
+    fn try_from_signed(rhs: i128) -> Result<Self, SizeConversionError> {
+        match u64::try_from(rhs) {
+            Ok(bytes) => Ok(Self(bytes)),
+            Err(_) if rhs < 0 => Err(SizeConversionError::Negative(rhs)),
+            Err(_) => Err(SizeConversionError::TooLarge(rhs)),
+        }
+    }
+}
+
+/// Converts a unit into exactly one of that unit's worth of bytes, which is
+/// handy for threshold comparisons. This is equivalent to `Size::from_units(1, unit)`
+/// and to `unit.size()`, which also works in `const` contexts.
+///
+/// ```rust
+/// # use repr_size::*;
+/// let file_size = Size::from(2_500_000_000u64);
+///
+/// assert!(file_size >= Units::Gigabytes.into());
+/// assert_eq!(Size::from(Units::Gigabytes), Size::from(1_000_000_000u64));
+/// ```
+impl From<Units> for Size {
+    fn from(rhs: Units) -> Self {
+        rhs.size()
+    }
+}
+
+/// Converts a non-zero capacity, such as one from an allocator API.
+///
+/// ```rust
+/// # use repr_size::*;
+/// # use std::num::NonZeroUsize;
+/// let capacity = NonZeroUsize::new(usize::MAX).unwrap();
+/// assert_eq!(Size::from(capacity), Size::from(usize::MAX));
+/// ```
+impl From<NonZeroUsize> for Size {
+    fn from(rhs: NonZeroUsize) -> Self {
+        Self(rhs.get() as u64)
+    }
+}
+
+/// Converts a size back into a non-zero capacity, failing for `Size::ZERO`
+/// or sizes that don't fit in this target's `usize`.
+///
+/// ```rust
+/// # use repr_size::*;
+/// # use std::num::NonZeroUsize;
+/// assert_eq!(NonZeroUsize::try_from(Size::from(64u64)), Ok(NonZeroUsize::new(64).unwrap()));
+/// assert!(NonZeroUsize::try_from(Size::ZERO).is_err());
+/// # assert_eq!(NonZeroUsize::try_from(Size::from(usize::MAX)).map(NonZeroUsize::get), Ok(usize::MAX));
+/// ```
+impl TryFrom<Size> for NonZeroUsize {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(rhs: Size) -> Result<Self, Self::Error> {
+        NonZeroUsize::try_from(usize::try_from(rhs.0)?)
+    }
+}
+
+impl TryFrom<isize> for Size {
+    type Error = SizeConversionError;
+
+    /// Will error if x < 0.
+    fn try_from(rhs: isize) -> Result<Self, Self::Error> {
+        Self::try_from_signed(rhs as i128)
+    }
+}
+
+/// Converts a signed byte count, such as one read from a database column.
+///
+/// ```rust
+/// # use repr_size::*;
+/// assert_eq!(Size::try_from(54222i64), Ok(Size::from(54222u64)));
+/// assert_eq!(Size::try_from(i64::MAX), Ok(Size::from(i64::MAX as u64)));
+/// assert_eq!(Size::try_from(-1i64), Err(SizeConversionError::Negative(-1)));
+/// assert_eq!(
+///     Size::try_from(i64::MIN),
+///     Err(SizeConversionError::Negative(i64::MIN as i128)),
+/// );
+/// ```
+impl TryFrom<i64> for Size {
+    type Error = SizeConversionError;
+
+    /// Will error if x < 0.
+    fn try_from(rhs: i64) -> Result<Self, Self::Error> {
+        Self::try_from_signed(rhs as i128)
+    }
+}
+
+/// Converts a float byte count. Equivalent to `Size::try_from_f64`.
+///
+/// ```rust
+/// # use repr_size::*;
+/// assert_eq!(Size::try_from(1.5e9), Ok(Size::from(1_500_000_000u64)));
+/// assert_eq!(Size::try_from(f64::NAN), Err(FloatSizeError::NaN));
+/// ```
+#[cfg(feature = "float")]
+impl TryFrom<f64> for Size {
+    type Error = FloatSizeError;
+
+    fn try_from(rhs: f64) -> Result<Self, Self::Error> {
+        Self::try_from_f64(rhs)
+    }
+}
+
+/// Converts a wide signed byte count.
+///
+/// ```rust
+/// # use repr_size::*;
+/// let max = u64::MAX as i128;
+///
+/// assert_eq!(Size::try_from(max), Ok(Size::MAX));
+/// assert_eq!(Size::try_from(max + 1), Err(SizeConversionError::TooLarge(max + 1)));
+/// assert_eq!(Size::try_from(-1i128), Err(SizeConversionError::Negative(-1)));
+/// # assert_eq!(Size::try_from(i128::MIN), Err(SizeConversionError::Negative(i128::MIN)));
+/// # assert_eq!(Size::try_from(i128::MAX), Err(SizeConversionError::TooLarge(i128::MAX)));
+/// # assert_eq!(
+/// #     Size::try_from(max + 1).unwrap_err().to_string(),
+/// #     "18446744073709551616 bytes is larger than the maximum size of 18446744073709551615 bytes",
+/// # );
+/// ```
+impl TryFrom<i128> for Size {
+    type Error = SizeConversionError;
+
+    /// Will error if x < 0 or x > `Size::MAX`.
+    fn try_from(rhs: i128) -> Result<Self, Self::Error> {
+        Self::try_from_signed(rhs)
     }
 }
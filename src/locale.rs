@@ -0,0 +1,155 @@
+use crate::Units;
+
+/// A language to name units in, for use with `SizeFormatter::locale`.
+///
+/// Only byte units are translated; bit units, and anything else a locale has no
+/// translation for, fall back to English.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let french = SizeFormatter::new().locale(Locale::Fr);
+///
+/// assert_eq!(french.format(Size::from(2_300_000u64)), "2.3 Mo");
+/// assert_eq!(french.unit_style(UnitStyle::Long).format(Size::from(2_300_000u64)), "2.3 mégaoctets");
+/// assert_eq!(french.unit_style(UnitStyle::Long).format(Size::KB), "1.0 kilooctet");
+/// # assert_eq!(french.base(Base::Binary).format(Size::MIB), "1.0 Mio");
+/// # assert_eq!(french.format(Size::ZERO), "0 o");
+/// # assert_eq!(french.unit_style(UnitStyle::Long).format(Size::ZERO), "0 octets");
+/// # assert_eq!(french.format_as(Size::from(12_500_000u64), Units::Megabits), "100.0 Mbit");
+///
+/// let german = SizeFormatter::new().locale(Locale::De).unit_style(UnitStyle::Long);
+/// assert_eq!(german.format(Size::from(2_300_000u64)), "2.3 Megabyte");
+/// # assert_eq!(german.format(Size::MB), "1.0 Megabyte");
+/// # assert_eq!(german.base(Base::Binary).format(Size::GIB), "1.0 Gibibyte");
+/// # assert_eq!(german.unit_style(UnitStyle::Symbol).format(Size::KB), "1.0 kB");
+///
+/// let spanish = SizeFormatter::new().locale(Locale::Es).unit_style(UnitStyle::Long);
+/// assert_eq!(spanish.format(Size::from(2_300_000u64)), "2.3 megabytes");
+/// # assert_eq!(spanish.format(Size::from(1u64)), "1 byte");
+///
+/// let japanese = SizeFormatter::new().locale(Locale::Ja).unit_style(UnitStyle::Long);
+/// assert_eq!(japanese.format(Size::from(2_300_000u64)), "2.3 メガバイト");
+/// # assert_eq!(japanese.format(Size::MB), "1.0 メガバイト");
+/// # assert_eq!(japanese.base(Base::Binary).format(Size::EIB), "1.0 エクスビバイト");
+///
+/// // Bit units have no translations, so they use the English names.
+/// assert_eq!(Locale::Ja.name_plural(Units::Megabits), "megabits");
+/// # assert_eq!(Locale::Fr.symbol(Units::Gigabits), "Gbit");
+/// # for unit in Units::ALL {
+/// #     assert_eq!(Locale::En.symbol(*unit), unit.symbol());
+/// #     assert_eq!(Locale::En.name_singular(*unit), unit.name_singular());
+/// #     assert_eq!(Locale::En.name_plural(*unit), unit.name_plural());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Locale {
+    /// English, which is the default.
+    En,
+    /// German.
+    De,
+    /// French, which counts in octets ("Mo") rather than bytes.
+    Fr,
+    /// Spanish.
+    Es,
+    /// Japanese.
+    Ja,
+}
+
+impl Locale {
+    /// Returns the preferred symbol for `unit` in this locale, such as "Mo" in French.
+    pub const fn symbol(&self, unit: Units) -> &'static str {
+        match self.names(unit) {
+            Some([symbol, _, _]) => symbol,
+            None => unit.symbol(),
+        }
+    }
+
+    /// Returns the name of one of `unit` in this locale.
+    pub const fn name_singular(&self, unit: Units) -> &'static str {
+        match self.names(unit) {
+            Some([_, singular, _]) => singular,
+            None => unit.name_singular(),
+        }
+    }
+
+    /// Returns the name of several of `unit` in this locale.
+    pub const fn name_plural(&self, unit: Units) -> &'static str {
+        match self.names(unit) {
+            Some([_, _, plural]) => plural,
+            None => unit.name_plural(),
+        }
+    }
+
+    /// The symbol, singular name and plural name of `unit`, if this locale translates it.
+    const fn names(&self, unit: Units) -> Option<[&'static str; 3]> {
+        match self {
+            Self::En => None,
+            Self::De => german(unit),
+            Self::Fr => french(unit),
+            // Spanish uses the English names.
+            Self::Es => None,
+            Self::Ja => japanese(unit),
+        }
+    }
+}
+
+const fn german(unit: Units) -> Option<[&'static str; 3]> {
+    // German uses the same form for one and many bytes.
+    Some(match unit {
+        Units::Bytes => ["B", "Byte", "Byte"],
+        Units::Kilobytes => ["kB", "Kilobyte", "Kilobyte"],
+        Units::Kibibytes => ["KiB", "Kibibyte", "Kibibyte"],
+        Units::Megabytes => ["MB", "Megabyte", "Megabyte"],
+        Units::Mebibytes => ["MiB", "Mebibyte", "Mebibyte"],
+        Units::Gigabytes => ["GB", "Gigabyte", "Gigabyte"],
+        Units::Gibibytes => ["GiB", "Gibibyte", "Gibibyte"],
+        Units::Terabytes => ["TB", "Terabyte", "Terabyte"],
+        Units::Tebibytes => ["TiB", "Tebibyte", "Tebibyte"],
+        Units::Petabytes => ["PB", "Petabyte", "Petabyte"],
+        Units::Pebibytes => ["PiB", "Pebibyte", "Pebibyte"],
+        Units::Exabytes => ["EB", "Exabyte", "Exabyte"],
+        Units::Exbibytes => ["EiB", "Exbibyte", "Exbibyte"],
+        _ => return None,
+    })
+}
+
+const fn french(unit: Units) -> Option<[&'static str; 3]> {
+    Some(match unit {
+        Units::Bytes => ["o", "octet", "octets"],
+        Units::Kilobytes => ["ko", "kilooctet", "kilooctets"],
+        Units::Kibibytes => ["Kio", "kibioctet", "kibioctets"],
+        Units::Megabytes => ["Mo", "mégaoctet", "mégaoctets"],
+        Units::Mebibytes => ["Mio", "mébioctet", "mébioctets"],
+        Units::Gigabytes => ["Go", "gigaoctet", "gigaoctets"],
+        Units::Gibibytes => ["Gio", "gibioctet", "gibioctets"],
+        Units::Terabytes => ["To", "téraoctet", "téraoctets"],
+        Units::Tebibytes => ["Tio", "tébioctet", "tébioctets"],
+        Units::Petabytes => ["Po", "pétaoctet", "pétaoctets"],
+        Units::Pebibytes => ["Pio", "pébioctet", "pébioctets"],
+        Units::Exabytes => ["Eo", "exaoctet", "exaoctets"],
+        Units::Exbibytes => ["Eio", "exbioctet", "exbioctets"],
+        _ => return None,
+    })
+}
+
+const fn japanese(unit: Units) -> Option<[&'static str; 3]> {
+    // Japanese doesn't inflect for number.
+    Some(match unit {
+        Units::Bytes => ["B", "バイト", "バイト"],
+        Units::Kilobytes => ["kB", "キロバイト", "キロバイト"],
+        Units::Kibibytes => ["KiB", "キビバイト", "キビバイト"],
+        Units::Megabytes => ["MB", "メガバイト", "メガバイト"],
+        Units::Mebibytes => ["MiB", "メビバイト", "メビバイト"],
+        Units::Gigabytes => ["GB", "ギガバイト", "ギガバイト"],
+        Units::Gibibytes => ["GiB", "ギビバイト", "ギビバイト"],
+        Units::Terabytes => ["TB", "テラバイト", "テラバイト"],
+        Units::Tebibytes => ["TiB", "テビバイト", "テビバイト"],
+        Units::Petabytes => ["PB", "ペタバイト", "ペタバイト"],
+        Units::Pebibytes => ["PiB", "ペビバイト", "ペビバイト"],
+        Units::Exabytes => ["EB", "エクサバイト", "エクサバイト"],
+        Units::Exbibytes => ["EiB", "エクスビバイト", "エクスビバイト"],
+        _ => return None,
+    })
+}
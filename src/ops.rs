@@ -0,0 +1,975 @@
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Rem, Sub, SubAssign};
+
+#[cfg(feature = "float")]
+use crate::FloatSizeError;
+use crate::{Size, Units};
+
+/// Implements `$trait<&Size> for Size`, `$trait<Size> for &Size` and
+/// `$trait<&Size> for &Size` by forwarding to `$trait<Size> for Size`.
+macro_rules! forward_ref_binop {
+    ($trait:ident, $method:ident) => {
+        impl $trait<&Size> for Size {
+            type Output = Size;
+
+            fn $method(self, rhs: &Size) -> Size {
+                $trait::$method(self, *rhs)
+            }
+        }
+
+        impl $trait<Size> for &Size {
+            type Output = Size;
+
+            fn $method(self, rhs: Size) -> Size {
+                $trait::$method(*self, rhs)
+            }
+        }
+
+        impl $trait<&Size> for &Size {
+            type Output = Size;
+
+            fn $method(self, rhs: &Size) -> Size {
+                $trait::$method(*self, *rhs)
+            }
+        }
+    };
+}
+
+/// Adds two sizes.
+///
+/// # Panics
+/// Panics in debug builds if the result is larger than `Size::MAX`, and wraps in
+/// release builds, just like integer addition. Use `Size::checked_add` to detect overflow.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let listing = [Size::from(54222u64), Size::from(1_200_000u64), Size::from(300u64)];
+///
+/// let mut total = Size::ZERO;
+/// for entry in &listing {
+///     total = total + entry;
+/// }
+/// assert_eq!(total.to_decimal_string(), "1.3 MB");
+/// # assert_eq!(listing[0] + listing[2], Size::from(54522u64));
+/// # assert_eq!(&listing[0] + &listing[2], Size::from(54522u64));
+/// # assert_eq!(&listing[0] + listing[2], Size::from(54522u64));
+/// # assert_eq!(Size::MAX + Size::ZERO, Size::MAX);
+/// ```
+impl Add for Size {
+    type Output = Size;
+
+    fn add(self, rhs: Size) -> Size {
+        Size(self.0 + rhs.0)
+    }
+}
+
+forward_ref_binop!(Add, add);
+
+/// Subtracts one size from another, producing a `Size`.
+///
+/// Use `Size::delta` instead when the result may be negative.
+///
+/// # Panics
+/// Panics in debug builds if `rhs` is larger than `self`, and wraps in release
+/// builds, just like integer subtraction. Use `Size::checked_sub` to detect underflow.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let disk = Size::from_units(500, Units::Gigabytes);
+/// let used = Size::from_units(120, Units::Gigabytes);
+///
+/// assert_eq!((disk - used).to_decimal_string(), "380.0 GB");
+/// # assert_eq!(&disk - &used, disk - used);
+/// # assert_eq!(disk - disk, Size::ZERO);
+/// ```
+///
+/// ```rust,should_panic
+/// # use repr_size::*;
+/// # if !cfg!(debug_assertions) { panic!() }
+/// let _ = Size::from(1u64) - Size::from(2u64);
+/// ```
+impl Sub for Size {
+    type Output = Size;
+
+    fn sub(self, rhs: Size) -> Size {
+        Size(self.0 - rhs.0)
+    }
+}
+
+forward_ref_binop!(Sub, sub);
+
+/// Adds a raw byte count to a size, with the same overflow behavior as `Size + Size`.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let header_len: usize = 24;
+/// let body = Size::from(1000u64);
+///
+/// assert_eq!(body + header_len, Size::from(1024u64));
+/// assert_eq!(header_len + body, Size::from(1024u64));
+/// # assert_eq!(body + 0usize, body);
+/// ```
+impl Add<usize> for Size {
+    type Output = Size;
+
+    fn add(self, rhs: usize) -> Size {
+        self + Size::from(rhs)
+    }
+}
+
+impl Add<Size> for usize {
+    type Output = Size;
+
+    fn add(self, rhs: Size) -> Size {
+        Size::from(self) + rhs
+    }
+}
+
+/// Subtracts a raw byte count from a size, with the same underflow behavior as `Size - Size`.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let packet = Size::from(1500u64);
+/// let header_len: usize = 40;
+///
+/// assert_eq!(packet - header_len, Size::from(1460u64));
+/// ```
+///
+/// ```rust,should_panic
+/// # use repr_size::*;
+/// # if !cfg!(debug_assertions) { panic!() }
+/// let _ = Size::from(10u64) - 11usize;
+/// ```
+impl Sub<usize> for Size {
+    type Output = Size;
+
+    fn sub(self, rhs: usize) -> Size {
+        self - Size::from(rhs)
+    }
+}
+
+/// Multiplies a size by a count, such as the number of records of that size.
+///
+/// # Panics
+/// Panics in debug builds if the result is larger than `Size::MAX`, and wraps in
+/// release builds, just like integer multiplication. Use `Size::checked_mul` to detect overflow.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let record = Size::from(512u64);
+///
+/// assert_eq!(record * 3, Size::from(1536u64));
+/// assert_eq!(3 * record, Size::from(1536u64));
+/// # assert_eq!(record * 0usize, Size::ZERO);
+/// ```
+impl Mul<usize> for Size {
+    type Output = Size;
+
+    fn mul(self, rhs: usize) -> Size {
+        Size(self.0 * rhs as u64)
+    }
+}
+
+impl Mul<Size> for usize {
+    type Output = Size;
+
+    fn mul(self, rhs: Size) -> Size {
+        rhs * self
+    }
+}
+
+/// Multiplies a unit by a count, so allocation sizes read naturally.
+/// This is equivalent to `Size::from_units`, including its overflow behavior.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let buf = 64 * Units::Kibibytes;
+///
+/// assert_eq!(buf, Size::from(65536u64));
+/// assert_eq!(Units::Megabytes * 3, Size::from(3_000_000u64));
+/// # assert_eq!(16383 * Units::Pebibytes, Size::from(16383u64 << 50));
+/// # assert_eq!(0 * Units::Pebibytes, Size::ZERO);
+/// ```
+///
+/// ```rust,should_panic
+/// # use repr_size::*;
+/// # if !cfg!(debug_assertions) { panic!() }
+/// let _ = 20_000 * Units::Petabytes;
+/// ```
+impl Mul<Units> for usize {
+    type Output = Size;
+
+    fn mul(self, rhs: Units) -> Size {
+        Size::from_units(self, rhs)
+    }
+}
+
+impl Mul<usize> for Units {
+    type Output = Size;
+
+    fn mul(self, rhs: usize) -> Size {
+        Size::from_units(rhs, self)
+    }
+}
+
+/// Divides a size into a number of equal parts, rounding down to a whole byte.
+///
+/// # Panics
+/// Panics if `rhs` is zero, just like integer division. Use `Size::checked_div` to avoid this.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let file = Size::from_units(1, Units::Gibibytes);
+/// let part = file / 10;
+///
+/// assert_eq!(part, Size::from(107_374_182u64)); // the 4 leftover bytes are truncated
+/// assert_eq!(part.to_binary_string(), "102.4 MiB");
+/// # assert_eq!(Size::from(9u64) / 10, Size::ZERO);
+/// ```
+///
+/// ```rust,should_panic
+/// # use repr_size::*;
+/// let _ = Size::from(10u64) / 0;
+/// ```
+impl Div<usize> for Size {
+    type Output = Size;
+
+    fn div(self, rhs: usize) -> Size {
+        Size(self.0 / rhs as u64)
+    }
+}
+
+/// Returns the bytes left over after dividing a size into whole `rhs`-sized pieces.
+///
+/// # Panics
+/// Panics if `rhs` is zero, just like integer remainder.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let file = Size::GIB + Size::from(5u64);
+/// let segment = 64 * Units::Mebibytes;
+///
+/// assert_eq!(file % segment, Size::from(5u64));
+/// # assert_eq!(Size::GIB % segment, Size::ZERO);
+/// # assert_eq!(Size::from(10u64) % Size::from(11u64), Size::from(10u64));
+/// # assert_eq!(&file % &segment, Size::from(5u64));
+/// ```
+///
+/// ```rust,should_panic
+/// # use repr_size::*;
+/// let _ = Size::from(10u64) % Size::ZERO;
+/// ```
+impl Rem for Size {
+    type Output = Size;
+
+    fn rem(self, rhs: Size) -> Size {
+        Size(self.0 % rhs.0)
+    }
+}
+
+forward_ref_binop!(Rem, rem);
+
+/// Adds a size to a running total in place, with the same overflow behavior as `Size + Size`.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// # let chunk_sizes = [Size::from(4096u64), Size::from(4096u64), Size::from(10u64)];
+/// let mut downloaded = Size::ZERO;
+/// for chunk_size in chunk_sizes {
+///     downloaded += chunk_size;
+/// }
+/// assert_eq!(downloaded, Size::from(8202u64));
+/// # let mut edge = Size::MAX;
+/// # edge += Size::ZERO;
+/// # assert_eq!(edge, Size::MAX + Size::ZERO);
+/// ```
+impl AddAssign for Size {
+    fn add_assign(&mut self, rhs: Size) {
+        *self = *self + rhs;
+    }
+}
+
+/// Adds a raw byte count to a size in place.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let chunks: [&[u8]; 3] = [b"hello", b", ", b"world"];
+///
+/// let mut size = Size::ZERO;
+/// for chunk in chunks {
+///     size += chunk.len();
+/// }
+/// assert_eq!(size, Size::from(12u64));
+/// ```
+impl AddAssign<usize> for Size {
+    fn add_assign(&mut self, rhs: usize) {
+        *self = *self + rhs;
+    }
+}
+
+/// Subtracts a size from a running total in place, with the same underflow behavior as `Size - Size`.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let mut remaining = Size::from(10_000u64);
+/// for chunk_size in [Size::from(4096u64), Size::from(4096u64)] {
+///     remaining -= chunk_size;
+/// }
+/// assert_eq!(remaining, Size::from(1808u64));
+/// # let mut edge = Size::MAX;
+/// # edge -= Size::MAX;
+/// # assert_eq!(edge, Size::MAX - Size::MAX);
+/// ```
+///
+/// ```rust,should_panic
+/// # use repr_size::*;
+/// # if !cfg!(debug_assertions) { panic!() }
+/// let mut remaining = Size::ZERO;
+/// remaining -= Size::from(1u64);
+/// ```
+impl SubAssign for Size {
+    fn sub_assign(&mut self, rhs: Size) {
+        *self = *self - rhs;
+    }
+}
+
+/// Multiplies a size by a count in place, with the same overflow behavior as `Size * usize`.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let mut buffer = Size::KIB;
+/// buffer *= 64;
+///
+/// assert_eq!(buffer, 64 * Units::Kibibytes);
+/// # let mut edge = Size::MAX;
+/// # edge *= 1;
+/// # assert_eq!(edge, Size::MAX * 1);
+/// # edge *= 0;
+/// # assert_eq!(edge, Size::MAX * 0);
+/// ```
+impl MulAssign<usize> for Size {
+    fn mul_assign(&mut self, rhs: usize) {
+        *self = *self * rhs;
+    }
+}
+
+impl Size {
+    /// Adds two sizes, returning `None` if the result would be larger than `Size::MAX`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let quota = Size::from_units(10, Units::Gibibytes);
+    /// let mut used = Size::from_units(9, Units::Gibibytes);
+    ///
+    /// let upload = Size::from_units(512, Units::Mebibytes);
+    /// match used.checked_add(upload) {
+    ///     Some(total) if total <= quota => used = total,
+    ///     _ => panic!("quota exceeded"),
+    /// }
+    /// assert_eq!(used.to_binary_string(), "9.5 GiB");
+    /// # assert_eq!(Size::MAX.checked_add(Size::from(1u64)), None);
+    /// # assert_eq!(Size::MAX.checked_add(Size::ZERO), Some(Size::MAX));
+    /// # assert_eq!(Size::ZERO.checked_add(Size::ZERO), Some(Size::ZERO));
+    /// ```
+    pub const fn checked_add(self, rhs: Size) -> Option<Size> {
+        match self.0.checked_add(rhs.0) {
+            Some(bytes) => Some(Size(bytes)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs` from this size, returning `None` if `rhs` is larger.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let quota = Size::from_units(10, Units::Gibibytes);
+    /// let used = Size::from_units(12, Units::Gibibytes);
+    ///
+    /// assert_eq!(quota.checked_sub(used), None); // over quota, nothing remaining
+    /// assert_eq!(used.checked_sub(quota), Some(Size::from_units(2, Units::Gibibytes)));
+    /// # assert_eq!(Size::ZERO.checked_sub(Size::from(1u64)), None);
+    /// # assert_eq!(Size::MAX.checked_sub(Size::MAX), Some(Size::ZERO));
+    /// ```
+    pub const fn checked_sub(self, rhs: Size) -> Option<Size> {
+        match self.0.checked_sub(rhs.0) {
+            Some(bytes) => Some(Size(bytes)),
+            None => None,
+        }
+    }
+
+    /// Multiplies this size by a count, returning `None` if the result would be
+    /// larger than `Size::MAX`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let record = Size::from(512u64);
+    ///
+    /// assert_eq!(record.checked_mul(1000), Some(Size::from(512_000u64)));
+    /// assert_eq!(Size::MAX.checked_mul(2), None);
+    /// # assert_eq!(Size::MAX.checked_mul(1), Some(Size::MAX));
+    /// # assert_eq!(Size::MAX.checked_mul(0), Some(Size::ZERO));
+    /// # assert_eq!(Size::ZERO.checked_mul(usize::MAX), Some(Size::ZERO));
+    /// ```
+    pub const fn checked_mul(self, rhs: usize) -> Option<Size> {
+        match self.0.checked_mul(rhs as u64) {
+            Some(bytes) => Some(Size(bytes)),
+            None => None,
+        }
+    }
+
+    /// Divides this size by a count, rounding down, returning `None` if `rhs` is zero.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let quota = Size::from_units(10, Units::Gibibytes);
+    /// let tenants = 4;
+    ///
+    /// assert_eq!(quota.checked_div(tenants).unwrap().to_binary_string(), "2.5 GiB");
+    /// assert_eq!(quota.checked_div(0), None);
+    /// # assert_eq!(Size::from(7u64).checked_div(2), Some(Size::from(3u64)));
+    /// # assert_eq!(Size::MAX.checked_div(1), Some(Size::MAX));
+    /// # assert_eq!(Size::ZERO.checked_div(3), Some(Size::ZERO));
+    /// ```
+    pub const fn checked_div(self, rhs: usize) -> Option<Size> {
+        match self.0.checked_div(rhs as u64) {
+            Some(bytes) => Some(Size(bytes)),
+            None => None,
+        }
+    }
+
+    /// Adds two sizes, clamping at `Size::MAX` instead of overflowing.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let mut downloaded = Size::from(u64::MAX - 10);
+    /// downloaded = downloaded.saturating_add(Size::from(4096u64));
+    ///
+    /// assert_eq!(downloaded, Size::MAX);
+    /// # assert_eq!(Size::from(1u64).saturating_add(Size::from(2u64)), Size::from(3u64));
+    /// # assert_eq!(Size::MAX.saturating_add(Size::MAX), Size::MAX);
+    /// ```
+    pub const fn saturating_add(self, rhs: Size) -> Size {
+        Size(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts `rhs` from this size, clamping at `Size::ZERO` instead of underflowing.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let expected = Size::from_units(10, Units::Megabytes);
+    /// let received = Size::from_units(12, Units::Megabytes);
+    ///
+    /// // More arrived than announced: nothing remaining, rather than petabytes.
+    /// assert_eq!(expected.saturating_sub(received), Size::ZERO);
+    /// assert_eq!(received.saturating_sub(expected).to_decimal_string(), "2.0 MB");
+    /// # assert_eq!(Size::ZERO.saturating_sub(Size::MAX), Size::ZERO);
+    /// # assert_eq!(Size::MAX.saturating_sub(Size::ZERO), Size::MAX);
+    /// ```
+    pub const fn saturating_sub(self, rhs: Size) -> Size {
+        Size(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Multiplies this size by a count, clamping at `Size::MAX` instead of overflowing.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(512u64).saturating_mul(3), Size::from(1536u64));
+    /// assert_eq!(Size::PIB.saturating_mul(1_000_000), Size::MAX);
+    /// # assert_eq!(Size::MAX.saturating_mul(0), Size::ZERO);
+    /// # assert_eq!(Size::MAX.saturating_mul(usize::MAX), Size::MAX);
+    /// ```
+    pub const fn saturating_mul(self, rhs: usize) -> Size {
+        Size(self.0.saturating_mul(rhs as u64))
+    }
+
+    /// Returns the magnitude of the difference between two sizes, regardless of
+    /// which is larger. This never panics or wraps.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let expected = Size::from_units(700, Units::Mebibytes);
+    /// let resumed = Size::from(733_500_000u64);
+    ///
+    /// assert!(resumed.abs_diff(expected) <= Size::MIB);
+    /// # assert_eq!(expected.abs_diff(resumed), resumed.abs_diff(expected));
+    /// # assert_eq!(expected.abs_diff(expected), Size::ZERO);
+    /// # assert_eq!(Size::ZERO.abs_diff(Size::from(5u64)), Size::from(5u64));
+    /// # assert_eq!(Size::MAX.abs_diff(Size::ZERO), Size::MAX);
+    /// # assert_eq!(Size::ZERO.abs_diff(Size::MAX), Size::MAX);
+    /// ```
+    pub const fn abs_diff(self, other: Size) -> Size {
+        Size(self.0.abs_diff(other.0))
+    }
+
+    /// Returns the average of two sizes, rounded down, without overflowing.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// // Bisect the largest request body a server accepts.
+    /// # let accepts = |body: Size| body <= Size::from(1_234_567u64);
+    /// let (mut low, mut high) = (Size::ZERO, Size::GIB);
+    /// while high - low > Size::from(1u64) {
+    ///     let mid = low.midpoint(high);
+    ///     if accepts(mid) { low = mid } else { high = mid }
+    /// }
+    /// assert_eq!(low, Size::from(1_234_567u64));
+    /// # assert_eq!(Size::MAX.midpoint(Size::MAX), Size::MAX);
+    /// # assert_eq!(Size::MAX.midpoint(Size::ZERO), Size::from(u64::MAX / 2));
+    /// # assert_eq!(Size::ZERO.midpoint(Size::from(1u64)), Size::ZERO);
+    /// ```
+    pub const fn midpoint(self, other: Size) -> Size {
+        // The shared bits plus half the differing ones, which can't overflow.
+        Size((self.0 & other.0) + ((self.0 ^ other.0) >> 1))
+    }
+
+    /// Linearly interpolates from this size towards `other`, where `t = 0.0` returns
+    /// this size and `t = 1.0` returns `other`, exactly.
+    ///
+    /// `t` is clamped to `[0.0, 1.0]`, and NaN is treated as `0.0`. In between the
+    /// result is computed in `f64` and rounded half to even.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let start = Size::from_units(10, Units::Megabytes);
+    /// let end = Size::from_units(20, Units::Megabytes);
+    ///
+    /// assert_eq!(start.lerp(end, 0.25), Size::from_units(12_500, Units::Kilobytes));
+    /// assert_eq!(end.lerp(start, 0.25), Size::from_units(17_500, Units::Kilobytes));
+    /// assert_eq!(start.lerp(end, 2.0), end);
+    /// # assert_eq!(start.lerp(end, -1.0), start);
+    /// # assert_eq!(start.lerp(end, f64::NAN), start);
+    /// # assert_eq!(Size::from(0u64).lerp(Size::from(5u64), 0.5), Size::from(2u64));
+    /// # assert_eq!(Size::ZERO.lerp(Size::MAX, 1.0), Size::MAX);
+    /// # assert_eq!(Size::MAX.lerp(Size::ZERO, 0.0), Size::MAX);
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn lerp(self, other: Size, t: f64) -> Size {
+        match t {
+            t if t.is_nan() || t <= 0.0 => self,
+            t if t >= 1.0 => other,
+            t => {
+                let bytes = self.0 as f64 + (other.0 as f64 - self.0 as f64) * t;
+                // Clamp away any rounding past either endpoint.
+                let (low, high) = (self.min(other), self.max(other));
+                Size::from_f64_lossy(bytes).clamp(low, high)
+            }
+        }
+    }
+
+    /// Returns the ratio of this size to `denominator`, such as the fraction of a disk in use.
+    ///
+    /// The division is done in `f64`, so sizes keep 53 bits of precision.
+    ///
+    /// # Panics
+    /// Panics if `denominator` is zero. Use `Size::checked_ratio` to avoid this.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let used = Size::from_units(120, Units::Gigabytes);
+    /// let total = Size::from_units(480, Units::Gigabytes);
+    ///
+    /// assert_eq!(used.ratio(total), 0.25);
+    /// # assert_eq!(total.ratio(total), 1.0);
+    /// # assert_eq!(Size::ZERO.ratio(total), 0.0);
+    /// # assert_eq!(Size::MAX.ratio(Size::MAX), 1.0);
+    /// # assert!((Size::from(u64::MAX / 3).ratio(Size::MAX) - 1.0 / 3.0).abs() < 1e-15);
+    /// # assert!((Size::from(u64::MAX - 1024).ratio(Size::MAX) - 1.0).abs() < 1e-15);
+    /// # assert_eq!(Size::MAX.ratio(Size::from(1u64)), 18446744073709551615.0);
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use repr_size::*;
+    /// let _ = Size::from(10u64).ratio(Size::ZERO);
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn ratio(self, denominator: Size) -> f64 {
+        self.checked_ratio(denominator)
+            .expect("attempt to take the ratio to a zero size")
+    }
+
+    /// Returns the ratio of this size to `denominator`, or `None` if `denominator` is zero.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let used = Size::from_units(3, Units::Gigabytes);
+    ///
+    /// assert_eq!(used.checked_ratio(Size::from_units(4, Units::Gigabytes)), Some(0.75));
+    /// assert_eq!(used.checked_ratio(Size::ZERO), None);
+    /// # assert_eq!(Size::ZERO.checked_ratio(Size::ZERO), None);
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn checked_ratio(self, denominator: Size) -> Option<f64> {
+        if denominator.is_zero() {
+            return None;
+        }
+        Some(self.0 as f64 / denominator.0 as f64)
+    }
+
+    /// Returns this size as a fraction of `total`, where `1.0` means the two are equal.
+    ///
+    /// Unlike `Size::ratio`, this returns `0.0` when `total` is zero. Values are
+    /// above `1.0` when this size is larger than `total`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let cache = Size::from_units(512, Units::Mebibytes);
+    /// let quota = Size::from_units(2, Units::Gibibytes);
+    ///
+    /// assert_eq!(cache.fraction_of(quota), 0.25);
+    /// assert_eq!(cache.fraction_of(Size::ZERO), 0.0);
+    /// # assert_eq!(quota.fraction_of(cache), 4.0);
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn fraction_of(&self, total: Size) -> f64 {
+        self.checked_ratio(total).unwrap_or(0.0)
+    }
+
+    /// Returns this size as a percentage of `total`, where `100.0` means the two are equal.
+    ///
+    /// Returns `0.0` when `total` is zero, and values above `100.0` when this size
+    /// is larger than `total`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let cache = Size::from_units(512, Units::Mebibytes);
+    /// let quota = Size::from_units(2, Units::Gibibytes);
+    ///
+    /// let label = format!("{} ({:.1}%)", cache.to_binary_string(), cache.percent_of(quota));
+    /// assert_eq!(label, "512.0 MiB (25.0%)");
+    /// # assert_eq!(quota.percent_of(cache), 400.0);
+    /// # assert_eq!(cache.percent_of(Size::ZERO), 0.0);
+    /// # assert_eq!(Size::ZERO.percent_of(Size::ZERO), 0.0);
+    /// # let total = Size::from_units(16_000, Units::Terabytes);
+    /// # assert_eq!(format!("{:.6}", (total - Size::from(1_000_000u64)).percent_of(total)), "100.000000");
+    /// # assert_eq!(format!("{:.6}", Size::from_units(3, Units::Terabytes).percent_of(total)), "0.018750");
+    /// # assert_eq!(Size::MAX.percent_of(Size::MAX), 100.0);
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn percent_of(&self, total: Size) -> f64 {
+        self.fraction_of(total) * 100.0
+    }
+
+    /// Divides this size into whole `divisor`-sized pieces, returning how many
+    /// pieces fit and how many bytes are left over.
+    ///
+    /// The count is a `u64` since small divisors can produce counts that don't
+    /// fit in a 32-bit `usize`.
+    ///
+    /// # Panics
+    /// Panics if `divisor` is zero. Use `Size::checked_div_rem` to avoid this.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let file = Size::GIB + Size::from(5u64);
+    /// let segment = 64 * Units::Mebibytes;
+    ///
+    /// let (full_segments, remainder) = file.div_rem(segment);
+    /// assert_eq!(full_segments, 16);
+    /// assert_eq!(remainder.to_string(), "5 B");
+    /// # assert_eq!(Size::GIB.div_rem(segment), (16, Size::ZERO));
+    /// # assert_eq!(Size::from(10u64).div_rem(Size::from(11u64)), (0, Size::from(10u64)));
+    /// # assert_eq!(Size::MAX.div_rem(Size::from(1u64)), (u64::MAX, Size::ZERO));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use repr_size::*;
+    /// let _ = Size::from(10u64).div_rem(Size::ZERO);
+    /// ```
+    pub const fn div_rem(self, divisor: Size) -> (u64, Size) {
+        match self.checked_div_rem(divisor) {
+            Some(result) => result,
+            None => panic!("attempt to divide by a zero size"),
+        }
+    }
+
+    /// Like `Size::div_rem`, but returns `None` if `divisor` is zero.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(10u64).checked_div_rem(Size::from(4u64)), Some((2, Size::from(2u64))));
+    /// assert_eq!(Size::from(10u64).checked_div_rem(Size::ZERO), None);
+    /// ```
+    pub const fn checked_div_rem(self, divisor: Size) -> Option<(u64, Size)> {
+        if divisor.0 == 0 {
+            return None;
+        }
+        Some((self.0 / divisor.0, Size(self.0 % divisor.0)))
+    }
+
+    /// Returns how many `block`-sized blocks are needed to hold this size, rounding up.
+    ///
+    /// # Panics
+    /// Panics if `block` is zero. Use `Size::checked_div_ceil` to avoid this.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let file = Size::from(10_000u64);
+    ///
+    /// assert_eq!(file.div_ceil(4 * Units::Kibibytes), 3);
+    /// # assert_eq!(Size::from(8192u64).div_ceil(Size::from(4096u64)), 2);
+    /// # assert_eq!(Size::ZERO.div_ceil(Size::from(4096u64)), 0);
+    /// # assert_eq!(Size::MAX.div_ceil(Size::MAX), 1);
+    /// ```
+    pub const fn div_ceil(self, block: Size) -> u64 {
+        match self.checked_div_ceil(block) {
+            Some(blocks) => blocks,
+            None => panic!("attempt to divide by a zero size"),
+        }
+    }
+
+    /// Like `Size::div_ceil`, but returns `None` if `block` is zero.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(10_000u64).checked_div_ceil(Size::from(4096u64)), Some(3));
+    /// assert_eq!(Size::from(10_000u64).checked_div_ceil(Size::ZERO), None);
+    /// ```
+    pub const fn checked_div_ceil(self, block: Size) -> Option<u64> {
+        if block.0 == 0 {
+            return None;
+        }
+        Some(self.0.div_ceil(block.0))
+    }
+
+    /// Returns how many whole `block`-sized blocks fit in this size, rounding down.
+    ///
+    /// # Panics
+    /// Panics if `block` is zero. Use `Size::checked_div_floor` to avoid this.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let file = Size::from(10_000u64);
+    ///
+    /// assert_eq!(file.div_floor(4 * Units::Kibibytes), 2);
+    /// # assert_eq!(Size::from(8192u64).div_floor(Size::from(4096u64)), 2);
+    /// # assert_eq!(Size::from(4095u64).div_floor(Size::from(4096u64)), 0);
+    /// ```
+    pub const fn div_floor(self, block: Size) -> u64 {
+        match self.checked_div_floor(block) {
+            Some(blocks) => blocks,
+            None => panic!("attempt to divide by a zero size"),
+        }
+    }
+
+    /// Like `Size::div_floor`, but returns `None` if `block` is zero.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(10_000u64).checked_div_floor(Size::from(4096u64)), Some(2));
+    /// assert_eq!(Size::from(10_000u64).checked_div_floor(Size::ZERO), None);
+    /// ```
+    pub const fn checked_div_floor(self, block: Size) -> Option<u64> {
+        self.0.checked_div(block.0)
+    }
+
+    /// Rounds this size up to the nearest multiple of `block`. Exact multiples are
+    /// returned unchanged.
+    ///
+    /// # Panics
+    /// Panics if `block` is zero, or in debug builds if the result is larger than
+    /// `Size::MAX`. Use `Size::checked_next_multiple_of` to avoid this.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let upload = Size::from(2_500_000u64);
+    ///
+    /// assert_eq!(upload.next_multiple_of(Size::MIB), 3 * Units::Mebibytes);
+    /// assert_eq!(Size::MIB.next_multiple_of(Size::MIB), Size::MIB);
+    /// # assert_eq!(Size::ZERO.next_multiple_of(Size::MIB), Size::ZERO);
+    /// ```
+    pub const fn next_multiple_of(self, block: Size) -> Size {
+        Size(self.0.next_multiple_of(block.0))
+    }
+
+    /// Like `Size::next_multiple_of`, but returns `None` if `block` is zero or
+    /// the result would be larger than `Size::MAX`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(4097u64).checked_next_multiple_of(Size::KIB), Some(Size::from(5120u64)));
+    /// assert_eq!(Size::from(4097u64).checked_next_multiple_of(Size::ZERO), None);
+    /// assert_eq!(Size::MAX.checked_next_multiple_of(Size::KIB), None);
+    /// ```
+    pub const fn checked_next_multiple_of(self, block: Size) -> Option<Size> {
+        match self.0.checked_next_multiple_of(block.0) {
+            Some(bytes) => Some(Size(bytes)),
+            None => None,
+        }
+    }
+
+    /// Rounds this size up to a multiple of `align`, which must be a power of two.
+    /// Returns `None` if the result would be larger than `Size::MAX`.
+    ///
+    /// # Panics
+    /// Panics in debug builds if `align` is not a power of two.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(4097u64).align_up(4096), Some(Size::from(8192u64)));
+    /// assert_eq!(Size::from(4096u64).align_up(4096), Some(Size::from(4096u64)));
+    /// assert_eq!(Size::MAX.align_up(8), None);
+    /// # assert_eq!(Size::ZERO.align_up(4096), Some(Size::ZERO));
+    /// # assert_eq!(Size::from(1u64).align_up(2 << 20), Some(2 * Units::Mebibytes));
+    /// # assert_eq!(Size::from(13u64).align_up(1), Some(Size::from(13u64)));
+    /// # assert_eq!(Size::from(u64::MAX - 7).align_up(8), Some(Size::from(u64::MAX - 7)));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use repr_size::*;
+    /// # if !cfg!(debug_assertions) { panic!() }
+    /// let _ = Size::from(100u64).align_up(24);
+    /// ```
+    pub const fn align_up(self, align: usize) -> Option<Size> {
+        debug_assert!(align.is_power_of_two(), "alignment must be a power of two");
+        let mask = align as u64 - 1;
+        match self.0.checked_add(mask) {
+            Some(bytes) => Some(Size(bytes & !mask)),
+            None => None,
+        }
+    }
+
+    /// Rounds this size down to a multiple of `align`, which must be a power of two.
+    ///
+    /// # Panics
+    /// Panics in debug builds if `align` is not a power of two.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(8191u64).align_down(4096), Size::from(4096u64));
+    /// assert_eq!(Size::from(4095u64).align_down(4096), Size::ZERO);
+    /// # assert_eq!(Size::MAX.align_down(8), Size::from(u64::MAX - 7));
+    /// ```
+    pub const fn align_down(self, align: usize) -> Size {
+        debug_assert!(align.is_power_of_two(), "alignment must be a power of two");
+        Size(self.0 & !(align as u64 - 1))
+    }
+
+    /// Multiplies this size by a float factor, rounding the fractional byte half to even.
+    ///
+    /// The multiplication is done in `f64`, so sizes above 2^53 bytes lose precision.
+    ///
+    /// # Panics
+    /// Like `Duration::mul_f64`, panics if the result is negative, NaN, or larger
+    /// than `Size::MAX`. Use `Size::try_mul_f64` to avoid this.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let current = Size::from_units(200, Units::Mebibytes);
+    ///
+    /// assert_eq!(current.mul_f64(1.5), Size::from_units(300, Units::Mebibytes));
+    /// assert_eq!(current.mul_f64(0.8).to_binary_string(), "160.0 MiB");
+    /// # assert_eq!(current.mul_f64(1.0), current);
+    /// # assert_eq!(current.mul_f64(0.0), Size::ZERO);
+    /// # assert_eq!(Size::from(5u64).mul_f64(0.5), Size::from(2u64));
+    /// # assert_eq!(Size::from(7u64).mul_f64(0.5), Size::from(4u64));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use repr_size::*;
+    /// let _ = Size::MAX.mul_f64(2.0);
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn mul_f64(self, factor: f64) -> Size {
+        self.try_mul_f64(factor).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Multiplies this size by a float factor like `Size::mul_f64`, returning an error
+    /// instead of panicking.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let current = Size::from_units(200, Units::Mebibytes);
+    ///
+    /// assert_eq!(current.try_mul_f64(1.5), Ok(Size::from_units(300, Units::Mebibytes)));
+    /// assert_eq!(current.try_mul_f64(-1.0), Err(FloatSizeError::Negative(-209715200.0)));
+    /// assert!(matches!(Size::MAX.try_mul_f64(2.0), Err(FloatSizeError::TooLarge(_))));
+    /// # assert_eq!(current.try_mul_f64(f64::NAN), Err(FloatSizeError::NaN));
+    /// # assert_eq!(current.try_mul_f64(f64::INFINITY), Err(FloatSizeError::Infinite));
+    /// # assert_eq!(Size::ZERO.try_mul_f64(f64::INFINITY), Err(FloatSizeError::NaN));
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn try_mul_f64(self, factor: f64) -> Result<Size, FloatSizeError> {
+        Size::try_from_f64(self.0 as f64 * factor)
+    }
+
+    /// Divides this size by a float divisor, rounding the fractional byte half to even.
+    ///
+    /// The division is done in `f64`, so sizes above 2^53 bytes lose precision.
+    ///
+    /// # Panics
+    /// Like `Duration::div_f64`, panics if the result is negative, NaN, infinite
+    /// (including division by zero) or larger than `Size::MAX`. Use
+    /// `Size::try_div_f64` to avoid this.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let current = Size::from_units(300, Units::Mebibytes);
+    ///
+    /// assert_eq!(current.div_f64(1.5), Size::from_units(200, Units::Mebibytes));
+    /// # assert_eq!(current.div_f64(1.0), current);
+    /// # assert_eq!(Size::from(5u64).div_f64(2.0), Size::from(2u64));
+    /// ```
+    ///
+    /// ```rust,should_panic
+    /// # use repr_size::*;
+    /// let _ = Size::from(10u64).div_f64(0.0);
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn div_f64(self, divisor: f64) -> Size {
+        self.try_div_f64(divisor).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Divides this size by a float divisor like `Size::div_f64`, returning an error
+    /// instead of panicking.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(3000u64).try_div_f64(1.5), Ok(Size::from(2000u64)));
+    /// assert_eq!(Size::from(3000u64).try_div_f64(0.0), Err(FloatSizeError::Infinite));
+    /// assert!(matches!(Size::MAX.try_div_f64(0.5), Err(FloatSizeError::TooLarge(_))));
+    /// # assert_eq!(Size::ZERO.try_div_f64(0.0), Err(FloatSizeError::NaN));
+    /// ```
+    #[cfg(feature = "float")]
+    pub fn try_div_f64(self, divisor: f64) -> Result<Size, FloatSizeError> {
+        Size::try_from_f64(self.0 as f64 / divisor)
+    }
+}
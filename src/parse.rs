@@ -0,0 +1,1408 @@
+use std::ffi::OsStr;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::{Base, Size, Units};
+
+/// The error returned when a string cannot be parsed as a size or a unit.
+///
+/// It's shared by `FromStr` for `Size`, `SizeDelta` and `Units`, and by
+/// `SizeParser`, `Size::parse_strict`, `Size::parse_lenient`, `Size::parse_jedec`,
+/// `Size::parse_cli`, `Size::parse_memparse`, `Size::parse_prefix`,
+/// `Size::parse_os`, `Units::parse_lenient` and `Units::parse_prefix`.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// assert_eq!("".parse::<Size>(), Err(ParseSizeError::Empty));
+/// assert_eq!("1.2.3 kB".parse::<Size>(), Err(ParseSizeError::InvalidNumber { at: 3 }));
+/// assert_eq!("12 GiBs".parse::<Size>(), Err(ParseSizeError::UnknownUnit("GiBs".to_owned())));
+/// assert_eq!("17 EiB".parse::<Size>(), Err(ParseSizeError::Overflow));
+/// assert_eq!("-1 kB".parse::<Size>(), Err(ParseSizeError::NegativeNotAllowed));
+///
+/// let err = "12 GiBs".parse::<Size>().unwrap_err();
+/// assert_eq!(err.suggestion(), Some(Units::Gibibytes));
+/// assert_eq!(err.to_string(), "unknown unit `GiBs`, did you mean `GiB`?");
+///
+/// let err = "12 parsecs".parse::<Size>().unwrap_err();
+/// assert_eq!(err.suggestion(), None);
+/// assert!(err.to_string().starts_with("unknown unit `parsecs`, expected one of B, kbit, Kibit, kB, KiB,"));
+/// # let suggest = |s: &str| s.parse::<Size>().unwrap_err().suggestion();
+/// # assert_eq!(suggest("1 gib"), Some(Units::Gibibytes));
+/// # assert_eq!(suggest("1 mb"), Some(Units::Megabytes));
+/// # assert_eq!(suggest("1 kilobites"), Some(Units::Kilobytes));
+/// # assert_eq!(suggest("1 megabyts"), Some(Units::Megabytes));
+/// # assert_eq!(suggest("1 gigabitz"), Some(Units::Gigabits));
+/// # assert_eq!(suggest("1 gigz"), Some(Units::Gigabytes));
+/// # assert_eq!(suggest("1 XB"), None);
+/// # assert_eq!(suggest("1 x"), None);
+/// # assert_eq!(suggest("1,5 MB"), None);
+/// # assert_eq!("1 mb".parse::<Size>().unwrap_err().to_string(), "unknown unit `mb`, did you mean `MB`?");
+/// # let err = |s: &str| s.parse::<Size>().unwrap_err();
+/// # assert_eq!(err("   "), ParseSizeError::Empty);
+/// # assert_eq!(err("\t\n"), ParseSizeError::Empty);
+/// # assert_eq!(err("kB"), ParseSizeError::InvalidNumber { at: 0 });
+/// # assert_eq!(err("  kB"), ParseSizeError::InvalidNumber { at: 2 });
+/// # assert_eq!(err("."), ParseSizeError::InvalidNumber { at: 0 });
+/// # assert_eq!(err(". kB"), ParseSizeError::InvalidNumber { at: 0 });
+/// # assert_eq!(err("+1 kB"), ParseSizeError::InvalidNumber { at: 0 });
+/// # assert_eq!(err("1..5 MB"), ParseSizeError::InvalidNumber { at: 2 });
+/// # assert_eq!(err(" 1.5.0"), ParseSizeError::InvalidNumber { at: 4 });
+/// # assert_eq!(err("1,5 MB"), ParseSizeError::InvalidNumber { at: 1 });
+/// # assert_eq!(err("1e3 B"), ParseSizeError::UnknownUnit("e3 B".to_owned()));
+/// # assert_eq!(err("1 XB"), ParseSizeError::UnknownUnit("XB".to_owned()));
+/// # assert_eq!(err("1 kB extra"), ParseSizeError::UnknownUnit("kB extra".to_owned()));
+/// # assert_eq!(err("5 mb"), ParseSizeError::UnknownUnit("mb".to_owned()));
+/// # assert_eq!(err(" -0"), ParseSizeError::NegativeNotAllowed);
+/// # assert_eq!(err("-kB"), ParseSizeError::NegativeNotAllowed);
+/// # assert_eq!(err("18446744073709551616"), ParseSizeError::Overflow);
+/// # assert_eq!(err("16 EiB"), ParseSizeError::Overflow);
+/// # assert_eq!(err("999999999999999999999999999999999999999999 B"), ParseSizeError::Overflow);
+/// # assert_eq!("-".parse::<SizeDelta>(), Err(ParseSizeError::Empty));
+/// # assert_eq!("--1 B".parse::<SizeDelta>(), Err(ParseSizeError::NegativeNotAllowed));
+/// # assert_eq!("-1.2.3 kB".parse::<SizeDelta>(), Err(ParseSizeError::InvalidNumber { at: 4 }));
+/// # assert_eq!(" +x".parse::<SizeDelta>(), Err(ParseSizeError::InvalidNumber { at: 2 }));
+/// # assert_eq!(Size::parse_jedec("1 XB"), Err(ParseSizeError::UnknownUnit("XB".to_owned())));
+/// # assert_eq!(Size::parse_jedec("16 EB"), Err(ParseSizeError::Overflow));
+/// # assert_eq!("".parse::<Units>(), Err(ParseSizeError::Empty));
+/// # assert_eq!("gib".parse::<Units>(), Err(ParseSizeError::UnknownUnit("gib".to_owned())));
+/// # assert_eq!(Units::parse_lenient("  "), Err(ParseSizeError::Empty));
+/// # assert_eq!(err("").to_string(), "cannot parse a size from an empty string");
+/// # assert_eq!(err("1..5").to_string(), "invalid number at byte 2");
+/// # assert_eq!(err("-1").to_string(), "sizes cannot be negative");
+/// # assert_eq!(err("17 EiB").to_string(), "size is larger than the maximum of 18446744073709551615 bytes");
+/// # fn assert_error<E: std::error::Error + Send + Sync + 'static>(_: &E) {}
+/// # assert_error(&err(""));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseSizeError {
+    /// The string was empty or only whitespace.
+    Empty,
+    /// The amount was missing or wasn't a number. `at` is the byte offset of the
+    /// first character that doesn't fit, in the string as given.
+    InvalidNumber {
+        /// Byte offset of the offending character.
+        at: usize,
+    },
+    /// The amount was followed by something that isn't a unit, which is kept as written.
+    UnknownUnit(String),
+    /// The size was larger than `Size::MAX`.
+    Overflow,
+    /// The amount was negative, which a `Size` can't hold.
+    NegativeNotAllowed,
+    /// The size wasn't a whole number of bytes, which `Size::parse_strict` doesn't round.
+    NotWholeBytes,
+    /// The string given to `Size::parse_os` wasn't valid Unicode.
+    NotUnicode {
+        /// Byte offset of the first sequence that isn't valid UTF-8.
+        at: usize,
+    },
+}
+
+impl Display for ParseSizeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse a size from an empty string"),
+            Self::InvalidNumber { at } => write!(f, "invalid number at byte {}", at),
+            Self::UnknownUnit(unit) => {
+                if let Some(suggestion) = self.suggestion() {
+                    return write!(f, "unknown unit `{}`, did you mean `{}`?", unit, suggestion);
+                }
+                write!(f, "unknown unit `{}`, expected one of ", unit)?;
+                for (i, unit) in Units::ALL.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", unit)?;
+                }
+                Ok(())
+            }
+            Self::Overflow => write!(f, "size is larger than the maximum of {} bytes", u64::MAX),
+            Self::NegativeNotAllowed => write!(f, "sizes cannot be negative"),
+            Self::NotWholeBytes => write!(f, "size is not a whole number of bytes"),
+            Self::NotUnicode { at } => write!(f, "invalid unicode at byte {}", at),
+        }
+    }
+}
+
+impl std::error::Error for ParseSizeError {}
+
+impl ParseSizeError {
+    /// Returns the unit an `UnknownUnit` was most likely meant to be, going by
+    /// its symbols, names and aliases with case ignored, or `None` if none is close.
+    pub fn suggestion(&self) -> Option<Units> {
+        let unit = match self {
+            Self::UnknownUnit(unit) => unit.to_ascii_lowercase(),
+            _ => return None,
+        };
+        // Allow one typo for every three characters, so short symbols have to
+        // match exactly but for case.
+        let mut best = None;
+        let mut best_distance = unit.chars().count() / 3 + 1;
+        // On a tie the first wins, so bytes come before bits to make "mb" suggest
+        // megabytes, and aliases come last.
+        let bytes = Units::ALL.iter().filter(|unit| !unit.is_bits());
+        let bits = Units::ALL.iter().filter(|unit| unit.is_bits());
+        let names = bytes.chain(bits).flat_map(|&candidate| {
+            [candidate.symbol(), candidate.name_singular(), candidate.name_plural()]
+                .map(|name| (name, candidate))
+        });
+        for (name, candidate) in names.chain(ALIASES.iter().copied()) {
+            let distance = edit_distance(&unit, &name.to_ascii_lowercase());
+            if distance < best_distance {
+                best = Some(candidate);
+                best_distance = distance;
+            }
+        }
+        best
+    }
+
+    /// Moves an `InvalidNumber` offset past `prefix` bytes the parser didn't see.
+    pub(crate) fn offset_by(self, prefix: usize) -> Self {
+        match self {
+            Self::InvalidNumber { at } => Self::InvalidNumber { at: at + prefix },
+            err => err,
+        }
+    }
+}
+
+impl FromStr for Size {
+    type Err = ParseSizeError;
+
+    /// Parses a size such as `"54.2 kB"`, `"1.5MiB"` or `"22000"`, which is in bytes.
+    ///
+    /// Fractions are rounded to the nearest byte, so rounded forms like
+    /// `to_string` don't always parse back to the same size. `Size::to_exact_string`
+    /// always does.
+    ///
+    /// The integer part can be grouped in threes, as in `"1,048,576 B"`. `SizeParser`
+    /// can loosen that or read a decimal comma.
+    ///
+    /// Whole numbers can also be written in hex, octal or binary with the prefixes
+    /// Rust uses, as in `"0x4000"` or `"0x10 MiB"`. Since "B" and "E" are hex digits,
+    /// leave a space before units that start with them, as in `"0x10 B"`.
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!("0x4000".parse::<Size>(), Ok(Size::from(16_384u64)));
+    /// assert_eq!("0o777".parse::<Size>(), Ok(Size::from(511u64)));
+    /// assert_eq!("0b1010 kB".parse::<Size>(), Ok(Size::from(10_000u64)));
+    /// assert_eq!("0x10 MiB".parse::<Size>(), Ok(Size::from_units(16, Units::Mebibytes)));
+    /// assert_eq!("0x".parse::<Size>(), Err(ParseSizeError::InvalidNumber { at: 2 }));
+    /// # assert_eq!("0x10MiB".parse::<Size>(), Ok(Size::from_units(16, Units::Mebibytes)));
+    /// # assert_eq!("0x10kB".parse::<Size>(), Ok(Size::from(16_000u64)));
+    /// # assert_eq!("0x10 B".parse::<Size>(), Ok(Size::from(16u64)));
+    /// # assert_eq!("0x10B".parse::<Size>(), Ok(Size::from(0x10Bu64)));
+    /// # assert_eq!("0xff_ff".parse::<Size>(), Ok(Size::from(65_535u64)));
+    /// # assert_eq!("0xFFFF".parse::<Size>(), Ok(Size::from(65_535u64)));
+    /// # assert_eq!("0o10 KiB".parse::<Size>(), Ok(Size::from(8192u64)));
+    /// # assert_eq!("0o17".parse::<Size>(), Ok(Size::from(15u64)));
+    /// # assert_eq!("0b11".parse::<Size>(), Ok(Size::from(3u64)));
+    /// # assert_eq!("0b1000 Mbit".parse::<Size>(), Ok(Size::MB));
+    /// # assert_eq!("0xffffffffffffffff".parse::<Size>(), Ok(Size::MAX));
+    /// # assert_eq!("0x10000000000000000".parse::<Size>(), Err(ParseSizeError::Overflow));
+    /// # assert_eq!("0xffffffffffffffffffffffffffffffffff".parse::<Size>(), Err(ParseSizeError::Overflow));
+    /// # assert_eq!("0x10 EiB".parse::<Size>(), Err(ParseSizeError::Overflow));
+    /// # assert_eq!("0o".parse::<Size>(), Err(ParseSizeError::InvalidNumber { at: 2 }));
+    /// # assert_eq!("0x kB".parse::<Size>(), Err(ParseSizeError::InvalidNumber { at: 2 }));
+    /// # assert_eq!("0x_1".parse::<Size>(), Err(ParseSizeError::InvalidNumber { at: 2 }));
+    /// # assert_eq!("0xg".parse::<Size>(), Err(ParseSizeError::InvalidNumber { at: 2 }));
+    /// # assert_eq!("0o8".parse::<Size>(), Err(ParseSizeError::InvalidNumber { at: 2 }));
+    /// # assert_eq!("0x1.8 MiB".parse::<Size>(), Err(ParseSizeError::InvalidNumber { at: 3 }));
+    /// # assert_eq!(" 0b12".parse::<Size>(), Err(ParseSizeError::UnknownUnit("2".to_owned())));
+    /// # assert_eq!("0X10".parse::<Size>(), Err(ParseSizeError::UnknownUnit("X10".to_owned())));
+    /// # assert_eq!("0b".parse::<Size>(), Ok(Size::ZERO));
+    /// # assert_eq!("0B".parse::<Size>(), Ok(Size::ZERO));
+    /// # assert_eq!("0bit".parse::<Size>(), Ok(Size::ZERO));
+    /// # assert_eq!("010 B".parse::<Size>(), Ok(Size::from(10u64)));
+    /// # assert_eq!("0.5 kB".parse::<Size>(), Ok(Size::from(500u64)));
+    /// # assert_eq!(SizeParser::new().grouping(Grouping::None).parse("0xff_ff"), Err(ParseSizeError::InvalidNumber { at: 4 }));
+    /// # assert_eq!(Size::parse_strict("0b1 b"), Err(ParseSizeError::NotWholeBytes));
+    /// # assert_eq!(Size::parse_sum("0x1 GiB 0x200 MiB"), Ok(Size::GIB + Size::from_units(512, Units::Mebibytes)));
+    /// ```
+    ///
+    /// Amounts in bits, such as `"100 Mbit"` or `"8Mb"`, are divided by 8. A lowercase
+    /// "b" is always bits and an uppercase "B" always bytes, and a single bit is written
+    /// "b" or "bit". Bits that don't make up a whole byte are rounded up to one, or
+    /// rejected by `Size::parse_strict`.
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!("100 Mbit".parse::<Size>(), Ok(Size::from(12_500_000u64)));
+    /// assert_eq!("8Mb".parse::<Size>(), Ok(Size::from(1_000_000u64)));
+    /// assert_eq!("8MB".parse::<Size>(), Ok(Size::from(8_000_000u64)));
+    /// assert_eq!("12 b".parse::<Size>(), Ok(Size::from(2u64)));
+    /// # assert_eq!("16 bit".parse::<Size>(), Ok(Size::from(2u64)));
+    /// # assert_eq!("1 b".parse::<Size>(), Ok(Size::from(1u64)));
+    /// # assert_eq!("0 b".parse::<Size>(), Ok(Size::ZERO));
+    /// # assert_eq!("0.5 b".parse::<Size>(), Ok(Size::from(1u64)));
+    /// # assert_eq!("8.5 b".parse::<Size>(), Ok(Size::from(2u64)));
+    /// # assert_eq!("1.5 Mbit".parse::<Size>(), Ok(Size::from(187_500u64)));
+    /// # assert_eq!("1.3 kbit".parse::<Size>(), Ok(Size::from(163u64)));
+    /// # assert_eq!("1.1 kbit".parse::<Size>(), Ok(Size::from(138u64)));
+    /// # assert_eq!("0.001 kbit".parse::<Size>(), Ok(Size::from(1u64)));
+    /// # assert_eq!("0.0001 kb".parse::<Size>(), Ok(Size::from(1u64)));
+    /// # assert_eq!("1 Kibit".parse::<Size>(), Ok(Size::from(128u64)));
+    /// # assert_eq!("1 Tbit".parse::<Size>(), Ok(Size::from(125_000_000_000u64)));
+    /// # assert_eq!("1.1 KiB".parse::<Size>(), Ok(Size::from(1126u64)));
+    /// # assert_eq!("0.0005 kB".parse::<Size>(), Ok(Size::from(1u64)));
+    /// # assert_eq!("0.0004 kB".parse::<Size>(), Ok(Size::ZERO));
+    /// # assert_eq!("147573952589676412920 b".parse::<Size>(), Ok(Size::MAX));
+    /// # assert_eq!("147573952589676412921 b".parse::<Size>(), Err(ParseSizeError::Overflow));
+    /// # assert_eq!("1 B".parse::<Size>(), Ok(Size::from(1u64)));
+    /// # assert_eq!("1 bits".parse::<Size>(), Err(ParseSizeError::UnknownUnit("bits".to_owned())));
+    /// # assert_eq!("1 Bit".parse::<Size>(), Err(ParseSizeError::UnknownUnit("Bit".to_owned())));
+    /// # assert_eq!("1 mb".parse::<Size>(), Err(ParseSizeError::UnknownUnit("mb".to_owned())));
+    /// # assert_eq!(Units::parse_lenient("Mb"), Ok(Units::Megabits));
+    /// # assert_eq!(Units::parse_lenient("MB"), Ok(Units::Megabytes));
+    /// ```
+    ///
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!("54.2 kB".parse::<Size>(), Ok(Size::from(54_200u64)));
+    /// assert_eq!("1.5MiB".parse::<Size>(), Ok(Size::from(1_572_864u64)));
+    /// assert_eq!("22000".parse::<Size>(), Ok(Size::from(22_000u64)));
+    /// assert!("-1 B".parse::<Size>().is_err());
+    /// # assert_eq!("18446744073709551615 B".parse::<Size>(), Ok(Size::MAX));
+    /// # assert!("18446744073709551616 B".parse::<Size>().is_err());
+    /// # assert!("16 EiB".parse::<Size>().is_err());
+    /// # assert_eq!(" 0 B ".parse::<Size>(), Ok(Size::ZERO));
+    /// # assert!("".parse::<Size>().is_err());
+    /// # assert!("1 XB".parse::<Size>().is_err());
+    /// ```
+    ///
+    /// Parsing what `to_decimal_string` or `to_binary_string` print lands within
+    /// rounding distance of the original size:
+    /// ```rust
+    /// # use repr_size::*;
+    /// let size = Size::from(1_100_000_000u64);
+    /// assert_eq!(size.to_decimal_string(), "1.1 GB");
+    /// assert_eq!(size.to_decimal_string().parse::<Size>(), Ok(size));
+    ///
+    /// let size = Size::from(1_234_567u64);
+    /// let parsed: Size = size.to_binary_string().parse().unwrap();
+    /// assert!(parsed.abs_diff(size).bytes() <= Units::Mebibytes.bytes() / 20);
+    /// assert_eq!(size.to_exact_string().parse::<Size>(), Ok(size));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_size(s)
+    }
+}
+
+/// Parses a size like `FromStr`, so `Size::try_from(arg)?` works where a
+/// `TryFrom` bound is needed.
+///
+/// ```rust
+/// # use repr_size::*;
+/// assert_eq!(Size::try_from("54.2 kB"), Ok(Size::from(54_200u64)));
+/// assert_eq!(Size::try_from("12 GiBs"), Err(ParseSizeError::UnknownUnit("GiBs".to_owned())));
+/// # for s in ["1,048,576 B", "0x10 MiB", "100 Mbit", "", "-1 B", "1.2.3"] {
+/// #     assert_eq!(Size::try_from(s), s.parse::<Size>());
+/// # }
+/// # fn parse_all<'a, T: TryFrom<&'a str>>(args: &[&'a str]) -> Result<Vec<T>, T::Error> {
+/// #     args.iter().map(|&arg| T::try_from(arg)).collect()
+/// # }
+/// # assert_eq!(parse_all::<Size>(&["1 kB", "2 kB"]), Ok(vec![Size::KB, Size::KB * 2]));
+/// ```
+impl TryFrom<&str> for Size {
+    type Error = ParseSizeError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        parse_size(s)
+    }
+}
+
+impl FromStr for Units {
+    type Err = ParseSizeError;
+
+    /// Parses a unit from the symbol its `Display` impl prints, such as `"KiB"`.
+    ///
+    /// Symbols are case-sensitive, since "Mb" (megabits) and "MB" (megabytes) are
+    /// different units. Use `Units::parse_lenient` for user input.
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!("GiB".parse::<Units>(), Ok(Units::Gibibytes));
+    /// assert_eq!("Mb".parse::<Units>(), Ok(Units::Megabits));
+    /// assert!("gib".parse::<Units>().is_err());
+    ///
+    /// for unit in Units::ALL {
+    ///     assert_eq!(unit.to_string().parse::<Units>(), Ok(*unit));
+    /// }
+    /// # assert!("".parse::<Units>().is_err());
+    /// # assert!(" KiB".parse::<Units>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseSizeError::Empty);
+        }
+        unit_from_symbol(s).ok_or_else(|| ParseSizeError::UnknownUnit(s.to_owned()))
+    }
+}
+
+impl Units {
+    /// Parses a unit from a symbol or a full name, ignoring case and surrounding
+    /// whitespace, such as `"KIB"`, `"Megabytes"` or `"gigabit"`.
+    ///
+    /// Symbols only ignore the case of their prefix, like the size parsers: a
+    /// trailing "b" is bits and "B" is bytes, so `"mb"` is megabits and `"KB"`
+    /// kilobytes. As no unit is a single bit, "b" and "bit" alone are unknown.
+    ///
+    /// A few colloquial names are accepted too, all decimal: "k" for kilobytes,
+    /// "meg" and "megs" for megabytes, and "gig" and "gigs" for gigabytes.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::parse_lenient("kilobytes"), Ok(Units::Kilobytes));
+    /// assert_eq!(Units::parse_lenient("MIB"), Ok(Units::Mebibytes));
+    /// assert_eq!(Units::parse_lenient("GB"), Ok(Units::Gigabytes));
+    /// assert_eq!(Units::parse_lenient("Mb"), Ok(Units::Megabits));
+    /// assert_eq!(Units::parse_lenient("mbit"), Ok(Units::Megabits));
+    /// # assert_eq!(Units::parse_lenient(" Byte "), Ok(Units::Bytes));
+    /// # assert_eq!(Units::parse_lenient("b"), Err(ParseSizeError::UnknownUnit("b".to_owned())));
+    /// # assert_eq!(Units::parse_lenient("BIT"), Err(ParseSizeError::UnknownUnit("BIT".to_owned())));
+    /// # assert_eq!(Units::parse_lenient("B"), Ok(Units::Bytes));
+    /// # assert_eq!(Units::parse_lenient("mb"), Ok(Units::Megabits));
+    /// # assert_eq!(Units::parse_lenient("Kb"), Ok(Units::Kilobits));
+    /// # assert_eq!(Units::parse_lenient("kB"), Ok(Units::Kilobytes));
+    /// # assert_eq!(Units::parse_lenient("kib"), Ok(Units::Kibibits));
+    /// # assert_eq!(Units::parse_lenient("KBIT"), Ok(Units::Kilobits));
+    /// # assert_eq!(Units::parse_lenient("KB"), Ok(Units::Kilobytes));
+    /// # assert_eq!(Units::parse_lenient("Kibibyte"), Ok(Units::Kibibytes));
+    /// # assert_eq!(Units::parse_lenient("EXBIBYTES"), Ok(Units::Exbibytes));
+    /// # assert_eq!(Units::parse_lenient("tebibits"), Ok(Units::Tebibits));
+    /// # assert_eq!(Units::parse_lenient("GIBIT"), Ok(Units::Gibibits));
+    /// # assert_eq!(Units::parse_lenient(" parsecs "), Err(ParseSizeError::UnknownUnit("parsecs".to_owned())));
+    /// ```
+    ///
+    /// Every unit's names parse in any case:
+    /// ```rust
+    /// # use repr_size::*;
+    /// for unit in Units::ALL {
+    ///     for name in [unit.name_singular(), unit.name_plural()] {
+    ///         assert_eq!(Units::parse_lenient(&name.to_uppercase()), Ok(*unit));
+    ///         assert_eq!(Units::parse_lenient(&name.to_lowercase()), Ok(*unit));
+    ///         let mixed: String = name
+    ///             .chars()
+    ///             .enumerate()
+    ///             .map(|(i, c)| if i % 2 == 0 { c.to_ascii_uppercase() } else { c })
+    ///             .collect();
+    ///         assert_eq!(Units::parse_lenient(&mixed), Ok(*unit));
+    ///     }
+    /// }
+    ///
+    /// let aliases = [
+    ///     ("k", Units::Kilobytes),
+    ///     ("meg", Units::Megabytes),
+    ///     ("megs", Units::Megabytes),
+    ///     ("gig", Units::Gigabytes),
+    ///     ("gigs", Units::Gigabytes),
+    /// ];
+    /// for (alias, unit) in aliases {
+    ///     assert_eq!(Units::parse_lenient(alias), Ok(unit));
+    ///     assert_eq!(Units::parse_lenient(&alias.to_uppercase()), Ok(unit));
+    /// }
+    /// ```
+    pub fn parse_lenient(s: &str) -> Result<Units, ParseSizeError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseSizeError::Empty);
+        }
+        unit_from_symbol(s)
+            .or_else(|| unit_from_name(s))
+            .or_else(|| unit_from_alias(s))
+            .ok_or_else(|| ParseSizeError::UnknownUnit(s.to_owned()))
+    }
+
+    /// Parses the unit symbol at the start of `s` and returns it with the rest of
+    /// `s`, without allocating. The symbol is the whole run of letters there, so
+    /// "GiBs" is not read as "GiB". Symbols are case-sensitive, as for `FromStr`.
+    /// Fails with `ParseSizeError::Empty` if `s` doesn't start with a symbol.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Units::parse_prefix("GiB && age<7d"), Ok((Units::Gibibytes, " && age<7d")));
+    /// assert_eq!(Units::parse_prefix("Mbit/s"), Ok((Units::Megabits, "/s")));
+    /// assert_eq!(Units::parse_prefix("KiB"), Ok((Units::Kibibytes, "")));
+    /// assert_eq!(Units::parse_prefix("GiBs"), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Units::parse_prefix(""), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Units::parse_prefix(" KiB"), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Units::parse_prefix("Kibit"), Ok((Units::Kibibits, "")));
+    /// # assert_eq!(Units::parse_prefix("kB"), Ok((Units::Kilobytes, "")));
+    /// # assert_eq!(Units::parse_prefix("b"), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Units::parse_prefix("Bytes"), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Units::parse_prefix("parsecs"), Err(ParseSizeError::Empty));
+    /// # assert_eq!(Units::parse_prefix("B2"), Ok((Units::Bytes, "2")));
+    /// # for unit in Units::ALL {
+    /// #     let symbol = format!("{}>=", unit);
+    /// #     assert_eq!(Units::parse_prefix(&symbol), Ok((*unit, ">=")));
+    /// # }
+    /// ```
+    pub fn parse_prefix(s: &str) -> Result<(Units, &str), ParseSizeError> {
+        let (symbol, rest) = s.split_at(letters_length(s));
+        match unit_from_symbol(symbol) {
+            Some(unit) => Ok((unit, rest)),
+            None => Err(ParseSizeError::Empty),
+        }
+    }
+}
+
+/// Colloquial unit names `Units::parse_lenient` accepts, ignoring case.
+const ALIASES: &[(&str, Units)] = &[
+    ("k", Units::Kilobytes),
+    ("meg", Units::Megabytes),
+    ("megs", Units::Megabytes),
+    ("gig", Units::Gigabytes),
+    ("gigs", Units::Gigabytes),
+];
+
+/// Looks up a unit by its symbol or its singular or plural name, ignoring case.
+/// Short symbols like "kB" or "Mb" only ignore the case of their prefix, since a
+/// trailing "b" is bits and "B" is bytes.
+fn unit_from_name(name: &str) -> Option<Units> {
+    Units::ALL.iter().copied().find(|unit| {
+        let symbol = unit.symbol();
+        let (prefix, short) = if unit.is_bits() {
+            (&symbol[..symbol.len() - 3], "b")
+        } else {
+            (&symbol[..symbol.len() - 1], "B")
+        };
+        name.strip_suffix(short).is_some_and(|rest| rest.eq_ignore_ascii_case(prefix))
+            || unit.is_bits() && symbol.eq_ignore_ascii_case(name)
+            || [unit.name_singular(), unit.name_plural()]
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(name))
+    })
+}
+
+/// Looks up a unit by one of `ALIASES`, ignoring case.
+fn unit_from_alias(alias: &str) -> Option<Units> {
+    ALIASES
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(alias))
+        .map(|&(_, unit)| unit)
+}
+
+/// Number of single-character insertions, deletions and substitutions that turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Looks up a unit by the symbol its `Display` impl prints, or a short bit symbol like "Mb".
+fn unit_from_symbol(symbol: &str) -> Option<Units> {
+    Some(match symbol {
+        "B" => Units::Bytes,
+        "kB" => Units::Kilobytes,
+        "KiB" => Units::Kibibytes,
+        "MB" => Units::Megabytes,
+        "MiB" => Units::Mebibytes,
+        "GB" => Units::Gigabytes,
+        "GiB" => Units::Gibibytes,
+        "TB" => Units::Terabytes,
+        "TiB" => Units::Tebibytes,
+        "PB" => Units::Petabytes,
+        "PiB" => Units::Pebibytes,
+        "EB" => Units::Exabytes,
+        "EiB" => Units::Exbibytes,
+        // Bits are always spelled with a lowercase "b", so "Mb" is never "MB".
+        "kb" | "kbit" => Units::Kilobits,
+        "Kib" | "Kibit" => Units::Kibibits,
+        "Mb" | "Mbit" => Units::Megabits,
+        "Mib" | "Mibit" => Units::Mebibits,
+        "Gb" | "Gbit" => Units::Gigabits,
+        "Gib" | "Gibit" => Units::Gibibits,
+        "Tb" | "Tbit" => Units::Terabits,
+        "Tib" | "Tibit" => Units::Tebibits,
+        _ => return None,
+    })
+}
+
+/// Looks up a unit by its JEDEC symbol, where "KB" means 1024 bytes, falling back
+/// to the standard symbols for IEC units.
+fn unit_from_jedec_symbol(symbol: &str) -> Option<Units> {
+    Some(match symbol {
+        "KB" | "kB" => Units::Kibibytes,
+        "MB" => Units::Mebibytes,
+        "GB" => Units::Gibibytes,
+        "TB" => Units::Tebibytes,
+        "PB" => Units::Pebibytes,
+        "EB" => Units::Exbibytes,
+        symbol => return unit_from_symbol(symbol),
+    })
+}
+
+/// Looks up a unit by a `dd`-style suffix, where a single letter like "K" or a
+/// letter followed by "iB" means a power of 1024, and one followed by "B" a power of 1000.
+fn unit_from_cli_symbol(symbol: &str) -> Option<Units> {
+    Some(match symbol {
+        "B" => Units::Bytes,
+        "K" | "k" | "KiB" | "kiB" => Units::Kibibytes,
+        "KB" | "kB" => Units::Kilobytes,
+        "M" | "MiB" => Units::Mebibytes,
+        "MB" => Units::Megabytes,
+        "G" | "GiB" => Units::Gibibytes,
+        "GB" => Units::Gigabytes,
+        "T" | "TiB" => Units::Tebibytes,
+        "TB" => Units::Terabytes,
+        "P" | "PiB" => Units::Pebibytes,
+        "PB" => Units::Petabytes,
+        "E" | "EiB" => Units::Exbibytes,
+        "EB" => Units::Exabytes,
+        _ => return None,
+    })
+}
+
+/// What one of a unit is worth, counted in bytes or, for bit units, in bits.
+#[derive(Debug, Clone, Copy)]
+struct Scale {
+    /// Bytes or bits in one of the unit.
+    per_unit: u128,
+    /// Bits in a byte for bit units, 100 for percentages, or 1.
+    per_byte: u128,
+    /// Whether a partial byte rounds up, as it does for bits, rather than to the
+    /// nearest byte.
+    round_up: bool,
+}
+
+impl Scale {
+    /// A single bit, written "b" or "bit".
+    const BIT: Scale = Scale {
+        per_unit: 1,
+        per_byte: 8,
+        round_up: true,
+    };
+
+    const fn of(unit: Units) -> Scale {
+        if unit.is_bits() {
+            Scale {
+                per_unit: unit.bytes() as u128 * 8,
+                per_byte: 8,
+                round_up: true,
+            }
+        } else {
+            Scale {
+                per_unit: unit.bytes() as u128,
+                per_byte: 1,
+                round_up: false,
+            }
+        }
+    }
+
+    /// One percent of `total`.
+    const fn percent_of(total: Size) -> Scale {
+        Scale {
+            per_unit: total.0 as u128,
+            per_byte: 100,
+            round_up: false,
+        }
+    }
+}
+
+/// How `SizeParser` treats digit grouping separators in the integer part of a
+/// number, which are `,`, `_` and the thin spaces U+2009 and U+202F.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Grouping {
+    /// No separators are allowed.
+    None,
+    /// Separators must split the number into groups of three digits, as in
+    /// "1,048,576" or "1_000_000", all with the same separator. This is the default.
+    Strict,
+    /// Separators between digits are removed, wherever they are.
+    Lenient,
+}
+
+/// Parses sizes with a chosen set of the parsing features, so a config format
+/// can accept exactly the forms it wants.
+///
+/// `SizeParser::new` reads exactly what `FromStr` for `Size` does: digits grouped
+/// in threes, hex, octal and binary literals, and bits, with case-sensitive unit
+/// symbols in their own base. `SizeParser::strict` allows only a decimal number and
+/// a unit symbol, and `SizeParser::lenient` allows everything. Each feature can be
+/// switched on or off from there.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let gateway = SizeParser::strict();
+/// let cli = SizeParser::lenient();
+///
+/// assert_eq!(cli.parse("1 gig"), Ok(Size::GB));
+/// assert_eq!(gateway.parse("1 gig"), Err(ParseSizeError::UnknownUnit("gig".to_owned())));
+///
+/// assert_eq!(cli.parse("1,048,576"), Ok(Size::MIB));
+/// assert_eq!(gateway.parse("1,048,576"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+/// assert_eq!(gateway.allow_grouping(true).parse("1,048,576"), Ok(Size::MIB));
+///
+/// assert_eq!(cli.parse("1 GiB 512 MiB"), Ok(Size::from_units(1536, Units::Mebibytes)));
+/// assert_eq!(gateway.parse("1 GiB 512 MiB"), Err(ParseSizeError::UnknownUnit("GiB 512 MiB".to_owned())));
+///
+/// assert_eq!(cli.parse("0x4000"), Ok(Size::from(16_384u64)));
+/// assert_eq!(gateway.parse("0x4000"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+///
+/// assert_eq!(cli.parse("100 Mbit"), Ok(Size::from(12_500_000u64)));
+/// assert_eq!(cli.allow_bits(false).parse("100 Mbit"), Err(ParseSizeError::UnknownUnit("Mbit".to_owned())));
+///
+/// assert_eq!(gateway.parse("2 MB"), Ok(Size::MB * 2));
+/// assert_eq!(gateway.decimal_suffix_base(Base::Binary).parse("2 MB"), Ok(Size::MIB * 2));
+/// # assert_eq!(SizeParser::default(), SizeParser::new());
+/// # for s in ["54.2 kB", "1,048,576 B", "0x10 MiB", "100 Mbit", "12 b", "1 gig", "1 GB 2 MB", "1,5 MB", "2 Kilobytes", "-1 B", "", "0b101"] {
+/// #     assert_eq!(SizeParser::new().parse(s), s.parse::<Size>(), "{}", s);
+/// # }
+/// # assert_eq!(gateway.parse("54.2 kB"), Ok(Size::from(54_200u64)));
+/// # assert_eq!(gateway.parse("  1.5 MiB "), Ok(Size::from(1_572_864u64)));
+/// # assert_eq!(gateway.parse("2 kilobytes"), Err(ParseSizeError::UnknownUnit("kilobytes".to_owned())));
+/// # assert_eq!(gateway.parse("2 kb"), Ok(Size::from(250u64)));
+/// # assert_eq!(gateway.parse("16 b"), Ok(Size::from(2u64)));
+/// # assert_eq!(gateway.allow_aliases(true).parse("1 GIG"), Ok(Size::GB));
+/// # assert_eq!(gateway.allow_aliases(true).parse("1 Gigabyte"), Err(ParseSizeError::UnknownUnit("Gigabyte".to_owned())));
+/// # assert_eq!(gateway.lenient_units(true).parse("1 Gigabyte"), Ok(Size::GB));
+/// # assert_eq!(gateway.lenient_units(true).parse("1 gig"), Err(ParseSizeError::UnknownUnit("gig".to_owned())));
+/// # assert_eq!(gateway.allow_radix(true).parse("0x4000"), Ok(Size::from(16_384u64)));
+/// # assert_eq!(gateway.allow_compound(true).parse("1 GiB 512 MiB"), Ok(Size::from_units(1536, Units::Mebibytes)));
+/// # assert_eq!(gateway.parse_sum("1 GiB 512 MiB"), Ok(Size::from_units(1536, Units::Mebibytes)));
+/// # assert_eq!(cli.allow_compound(false).parse("1 GiB 512 MiB"), Err(ParseSizeError::UnknownUnit("GiB 512 MiB".to_owned())));
+/// # assert_eq!(cli.parse("1,00,000"), Ok(Size::from(100_000u64)));
+/// # assert_eq!(cli.parse("3 GIGS + 2 megs"), Ok(Size::from(3_002_000_000u64)));
+/// # assert_eq!(cli.allow_bits(false).parse("16 b"), Err(ParseSizeError::UnknownUnit("b".to_owned())));
+/// # assert_eq!(cli.allow_bits(false).parse("16 bit"), Err(ParseSizeError::UnknownUnit("bit".to_owned())));
+/// # assert_eq!(cli.allow_bits(false).parse("1 megabit"), Err(ParseSizeError::UnknownUnit("megabit".to_owned())));
+/// # assert_eq!(SizeParser::new().allow_radix(false).parse("0b1"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+/// # assert_eq!(SizeParser::new().allow_radix(false).parse("0b"), Ok(Size::ZERO));
+/// # assert_eq!(SizeParser::new().allow_radix(false).parse("0 B"), Ok(Size::ZERO));
+/// # let binary = SizeParser::new().decimal_suffix_base(Base::Binary);
+/// # assert_eq!(binary.parse("1 kB"), Ok(Size::KIB));
+/// # assert_eq!(binary.parse("1 KB"), Ok(Size::KIB));
+/// # assert_eq!(binary.parse("1 KiB"), Ok(Size::KIB));
+/// # assert_eq!(binary.parse("1 EB"), Ok(Size::EIB));
+/// # assert_eq!(binary.parse("1 Mbit"), Ok(Size::from(125_000u64)));
+/// # assert_eq!(binary.parse("1 B"), Ok(Size::from(1u64)));
+/// # assert_eq!(binary.allow_aliases(true).parse("1 gig"), Ok(Size::GIB));
+/// # assert_eq!(binary.lenient_units(true).parse("1 megabytes"), Ok(Size::MIB));
+/// # assert_eq!(SizeParser::new().parse("1 KB"), Err(ParseSizeError::UnknownUnit("KB".to_owned())));
+/// ```
+///
+/// Digits can be grouped with `,`, `_` and thin spaces:
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let parser = SizeParser::new();
+///
+/// assert_eq!(parser.parse("1,048,576 B"), Ok(Size::MIB));
+/// assert_eq!(parser.parse("1_000_000"), Ok(Size::MB));
+/// assert_eq!(parser.parse("1,000.5 kB"), Ok(Size::from(1_000_500u64)));
+/// assert_eq!(parser.parse("1,00,000 B"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+/// assert_eq!(parser.grouping(Grouping::Lenient).parse("1,00,000 B"), Ok(Size::from(100_000u64)));
+/// assert_eq!(parser.grouping(Grouping::None).parse("1,000 B"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+/// # assert_eq!(parser.parse("1\u{2009}048\u{2009}576 B"), Ok(Size::MIB));
+/// # assert_eq!(parser.parse("1\u{202F}000 kB"), Ok(Size::MB));
+/// # assert_eq!(parser.parse("10,000,000,000"), Ok(Size::from(10_000_000_000u64)));
+/// # assert_eq!(parser.parse("999,999 B"), Ok(Size::from(999_999u64)));
+/// # assert_eq!(parser.parse("1000,000 B"), Err(ParseSizeError::InvalidNumber { at: 4 }));
+/// # assert_eq!(parser.parse("1,0000 B"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+/// # assert_eq!(parser.parse("1,000_000 B"), Err(ParseSizeError::InvalidNumber { at: 5 }));
+/// # assert_eq!(parser.parse("1,000.000,5 B"), Err(ParseSizeError::InvalidNumber { at: 9 }));
+/// # assert_eq!(parser.parse("1.000_5 B"), Err(ParseSizeError::InvalidNumber { at: 5 }));
+/// # assert_eq!(parser.parse("1,,000 B"), Err(ParseSizeError::UnknownUnit(",,000 B".to_owned())));
+/// # assert_eq!(parser.parse("_1 B"), Err(ParseSizeError::InvalidNumber { at: 0 }));
+/// # assert_eq!(parser.parse("1_ B"), Err(ParseSizeError::UnknownUnit("_ B".to_owned())));
+/// # assert_eq!(parser.parse("1,000kB"), Ok(Size::MB));
+/// # assert_eq!(parser.parse(" 2,000 B"), Ok(Size::from(2000u64)));
+/// # assert_eq!(parser.parse("54.2\u{202F}kB"), Ok(Size::from(54_200u64)));
+/// # assert_eq!(parser.grouping(Grouping::Lenient).parse("1_0_0"), Ok(Size::from(100u64)));
+/// # assert_eq!(parser.grouping(Grouping::Lenient).parse("1,000"), Ok(Size::from(1000u64)));
+/// # assert_eq!(parser.grouping(Grouping::Lenient).parse("1.0_5 kB"), Err(ParseSizeError::InvalidNumber { at: 3 }));
+/// # assert_eq!(parser.grouping(Grouping::None).parse("1000 B"), Ok(Size::from(1000u64)));
+/// # assert_eq!(parser.grouping(Grouping::None).parse("1_000"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+/// # assert_eq!("1,048,576 B".parse::<Size>(), parser.parse("1,048,576 B"));
+/// # assert_eq!("-1,000 kB".parse::<SizeDelta>().map(|delta| delta.abs()), Ok(Size::MB));
+/// # assert_eq!(Size::parse_jedec("1,024 KB"), Ok(Size::MIB));
+/// # assert_eq!(Size::parse_strict("1,000 b"), Ok(Size::from(125u64)));
+/// ```
+///
+/// A comma on its own, as in "1,000" or "1,5", could be grouping or a decimal
+/// comma. It's read as grouping when it's followed by a unit, a fraction or
+/// another group. Otherwise it's a decimal comma if `decimal_comma` is set, and
+/// an error if not, except for lenient grouping, which removes it:
+/// ```rust
+/// # use repr_size::*;
+/// let parser = SizeParser::new();
+/// let european = parser.decimal_comma(true);
+///
+/// assert_eq!(parser.parse("1,000"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+/// assert_eq!(european.parse("1,000"), Ok(Size::from(1u64)));
+/// assert_eq!(european.parse("1,5 MB"), Ok(Size::from(1_500_000u64)));
+/// assert_eq!(european.parse("1,000 MB"), Ok(Size::GB));
+/// assert_eq!(european.parse("1,000,000"), Ok(Size::MB));
+/// # assert_eq!(parser.parse("1,000 B"), Ok(Size::from(1000u64)));
+/// # assert_eq!(parser.parse("1,000,000"), Ok(Size::MB));
+/// # assert_eq!(parser.parse("1,000.0"), Ok(Size::from(1000u64)));
+/// # assert_eq!(parser.parse("1,5 MB"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+/// # assert_eq!(parser.parse("1,5"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+/// # assert_eq!(parser.grouping(Grouping::Lenient).parse("1,5 MB"), Ok(Size::from(15_000_000u64)));
+/// # assert_eq!(european.parse("1,5"), Ok(Size::from(2u64)));
+/// # assert_eq!(european.parse("0,25 KiB"), Ok(Size::from(256u64)));
+/// # assert_eq!(european.parse("1_000,5 kB"), Ok(Size::from(1_000_500u64)));
+/// # assert_eq!(european.parse("1,000.5 kB"), Ok(Size::from(1_000_500u64)));
+/// # assert_eq!(european.parse("1,5,0 MB"), Err(ParseSizeError::InvalidNumber { at: 1 }));
+/// # assert_eq!(european.parse("1,5_0 MB"), Err(ParseSizeError::InvalidNumber { at: 3 }));
+/// # assert_eq!(european.grouping(Grouping::Lenient).parse("1,5 MB"), Ok(Size::from(1_500_000u64)));
+/// # assert_eq!(european.grouping(Grouping::None).parse("1,000 MB"), Ok(Size::MB));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SizeParser {
+    grouping: Grouping,
+    decimal_comma: bool,
+    exact: bool,
+    lenient_units: bool,
+    aliases: bool,
+    radix: bool,
+    bits: bool,
+    compound: bool,
+    /// The base that decimal byte symbols like "MB" are read in.
+    decimal_suffix_base: Base,
+    /// The unit of a number written without one.
+    default_unit: Units,
+}
+
+impl Default for SizeParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SizeParser {
+    /// Creates a parser that reads what `FromStr` for `Size` does.
+    pub const fn new() -> Self {
+        Self {
+            grouping: Grouping::Strict,
+            decimal_comma: false,
+            exact: false,
+            lenient_units: false,
+            aliases: false,
+            radix: true,
+            bits: true,
+            compound: false,
+            decimal_suffix_base: Base::Decimal,
+            default_unit: Units::Bytes,
+        }
+    }
+
+    /// Creates a parser that only reads a decimal number, possibly with a
+    /// fraction, and an optional unit symbol, such as "54.2 kB".
+    pub const fn strict() -> Self {
+        Self {
+            grouping: Grouping::None,
+            radix: false,
+            ..Self::new()
+        }
+    }
+
+    /// Creates a parser that reads everything it can: digits grouped in any way,
+    /// units in any case, names and aliases, literals, and sums.
+    pub const fn lenient() -> Self {
+        Self {
+            grouping: Grouping::Lenient,
+            lenient_units: true,
+            aliases: true,
+            compound: true,
+            ..Self::new()
+        }
+    }
+
+    /// Sets which digit grouping separators are allowed.
+    pub const fn grouping(self, grouping: Grouping) -> Self {
+        Self { grouping, ..self }
+    }
+
+    /// Sets whether digits can be grouped in threes, the same as
+    /// `grouping(Grouping::Strict)` or `grouping(Grouping::None)`.
+    pub const fn allow_grouping(self, allow: bool) -> Self {
+        let grouping = if allow { Grouping::Strict } else { Grouping::None };
+        Self { grouping, ..self }
+    }
+
+    /// Sets whether a single comma that isn't grouping digits is the decimal mark,
+    /// so "1,5 MB" is 1.5 MB.
+    ///
+    /// With `Grouping::None`, a single comma is always the decimal mark.
+    pub const fn decimal_comma(self, decimal_comma: bool) -> Self {
+        Self {
+            decimal_comma,
+            ..self
+        }
+    }
+
+    /// Sets whether to fail with `ParseSizeError::NotWholeBytes` rather than round
+    /// a size that isn't a whole number of bytes, as `Size::parse_strict` does.
+    pub const fn exact(self, exact: bool) -> Self {
+        Self { exact, ..self }
+    }
+
+    /// Sets whether unit symbols can be in any case and units can be spelled out
+    /// in the singular or plural, as in "2 Kilobytes" or "512 MIB".
+    ///
+    /// Only the prefix of a symbol can be in any case: a trailing "b" is still
+    /// bits and "B" bytes, so "mb" is megabits and "KB" kilobytes.
+    pub const fn lenient_units(self, lenient_units: bool) -> Self {
+        Self {
+            lenient_units,
+            ..self
+        }
+    }
+
+    /// Sets whether the colloquial unit names "k", "meg", "megs", "gig" and "gigs"
+    /// are allowed, in any case.
+    pub const fn allow_aliases(self, allow: bool) -> Self {
+        Self {
+            aliases: allow,
+            ..self
+        }
+    }
+
+    /// Sets whether whole numbers can be written in hex, octal or binary, as in "0x4000".
+    pub const fn allow_radix(self, allow: bool) -> Self {
+        Self {
+            radix: allow,
+            ..self
+        }
+    }
+
+    /// Sets whether sizes can be given in bits, as in "100 Mbit" or "12 b".
+    pub const fn allow_bits(self, allow: bool) -> Self {
+        Self { bits: allow, ..self }
+    }
+
+    /// Sets whether `parse` reads sums like `parse_sum` does, as in "1 GiB 512 MiB".
+    pub const fn allow_compound(self, allow: bool) -> Self {
+        Self {
+            compound: allow,
+            ..self
+        }
+    }
+
+    /// Sets the base decimal byte symbols are read in. `Base::Decimal`, the
+    /// default, reads "kB", "MB", "GB" and so on as powers of 1000. `Base::Binary`
+    /// reads them, and "KB", as powers of 1024, for files written by tools that use
+    /// the JEDEC meaning. IEC symbols like "MiB" and bits keep their meaning.
+    ///
+    /// `Size::to_jedec_string` writes what this reads back with `Base::Binary`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let correct = SizeParser::new();
+    /// let compatible = SizeParser::new().decimal_suffix_base(Base::Binary);
+    ///
+    /// assert_eq!(correct.parse("512 MB"), Ok(Size::from(512_000_000u64)));
+    /// assert_eq!(compatible.parse("512 MB"), Ok(Size::from(536_870_912u64)));
+    ///
+    /// assert_eq!(correct.parse("512 MiB"), Ok(Size::from(536_870_912u64)));
+    /// assert_eq!(compatible.parse("512 MiB"), Ok(Size::from(536_870_912u64)));
+    ///
+    /// let size = Size::from(1_572_864u64);
+    /// assert_eq!(size.to_jedec_string(), "1.5 MB");
+    /// assert_eq!(compatible.parse(&size.to_jedec_string()), Ok(size));
+    /// # for s in ["1 KiB", "1.5 GiB", "3 TiB", "1 EiB", "12 b", "100 Mbit", "1 Gibit", "7 B", "7"] {
+    /// #     assert_eq!(compatible.parse(s), correct.parse(s), "{}", s);
+    /// # }
+    /// # assert_eq!(correct.decimal_suffix_base(Base::Decimal), correct);
+    /// # assert_eq!(compatible.parse("1 kB"), Ok(Size::KIB));
+    /// # assert_eq!(compatible.parse("1 KB"), Ok(Size::KIB));
+    /// # assert_eq!(correct.parse("1 KB"), Err(ParseSizeError::UnknownUnit("KB".to_owned())));
+    /// # assert_eq!(compatible.parse("16 EB"), Err(ParseSizeError::Overflow));
+    /// ```
+    pub const fn decimal_suffix_base(self, decimal_suffix_base: Base) -> Self {
+        Self {
+            decimal_suffix_base,
+            ..self
+        }
+    }
+
+    /// Sets the base decimal byte symbols are read in, the same as `decimal_suffix_base`.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// let compatible = SizeParser::new().default_base(Base::Binary);
+    ///
+    /// assert_eq!(compatible, SizeParser::new().decimal_suffix_base(Base::Binary));
+    /// assert_eq!(compatible.parse("2 MB"), Ok(Size::MIB * 2));
+    /// ```
+    pub const fn default_base(self, base: Base) -> Self {
+        self.decimal_suffix_base(base)
+    }
+
+    /// Sets the unit of a number written without one, which is bytes by default.
+    /// A unit in the string still wins, and a fraction is scaled by the default
+    /// unit and rounded like any other.
+    ///
+    /// See `Size::parse_with_default_unit`.
+    pub const fn default_unit(self, default_unit: Units) -> Self {
+        Self { default_unit, ..self }
+    }
+
+    /// Parses a size such as `"1,048,576 B"` or `"54.2 kB"`.
+    pub fn parse(&self, s: &str) -> Result<Size, ParseSizeError> {
+        let lookup = |symbol: &str| self.scale_from_symbol(symbol);
+        if self.compound {
+            self.parse_sum_with(s, &lookup)
+        } else {
+            self.parse_with(s, &lookup)
+        }
+    }
+
+    /// Parses a sum of sizes such as `"1 GiB 512 MiB 7 B"` or `"1GiB+512MiB"`,
+    /// like the ones `Size::to_breakdown_string` writes, whether or not
+    /// `allow_compound` is set.
+    ///
+    /// Terms are separated by whitespace, a "+", or both, and are each parsed like
+    /// `parse`. They can be in any order and repeat units. The total is checked
+    /// for overflow.
+    pub fn parse_sum(&self, s: &str) -> Result<Size, ParseSizeError> {
+        self.allow_compound(true).parse(s)
+    }
+
+    /// Parses the longest size at the start of `s`, such as the "2GiB" in
+    /// `"2GiB && age<7d"`, and returns it with the rest of `s`, without allocating.
+    ///
+    /// Leading whitespace is skipped, and whitespace before a unit is only consumed
+    /// along with the unit. The unit is the whole run of letters after the number,
+    /// so "512 bytes" stops before "bytes" rather than reading "b" as bits. Sums
+    /// aren't read, even with `allow_compound`. Fails with `ParseSizeError::Empty`
+    /// if `s` doesn't start with a number.
+    ///
+    /// See `Size::parse_prefix`.
+    pub fn parse_prefix<'a>(&self, s: &'a str) -> Result<(Size, &'a str), ParseSizeError> {
+        let lookup = |symbol: &str| self.scale_from_symbol(symbol);
+        let start = s.len() - s.trim_start().len();
+        let rest = &s[start..];
+        if rest.starts_with('-') {
+            return Err(ParseSizeError::NegativeNotAllowed);
+        }
+        let mut number = match radix_prefix(rest) {
+            Some(radix) if self.radix && rest[2..].starts_with(|c: char| c.is_digit(radix)) => rest[2..]
+                .find(|c: char| !c.is_digit(radix) && c != '_')
+                .map_or(rest.len(), |end| 2 + end),
+            _ => number_end(rest),
+        };
+        if !rest[..number].contains(|c: char| c.is_ascii_digit()) {
+            return Err(ParseSizeError::Empty);
+        }
+        loop {
+            let end = start + number + symbol_length(&rest[number..], &lookup);
+            match self.parse_with(&s[..end], &lookup) {
+                Ok(size) => return Ok((size, &s[end..])),
+                // The number ends before the first character that doesn't fit,
+                // such as the second point in "1.2.3".
+                Err(ParseSizeError::InvalidNumber { at }) if at > start && at < start + number => {
+                    number = at - start;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Parses a size like `parse`, or a percentage of `total` such as `"50%"`.
+    ///
+    /// See `Size::parse_or_percent`.
+    pub fn parse_or_percent(&self, s: &str, total: Size) -> Result<Size, ParseSizeError> {
+        if !s.trim_end().ends_with('%') {
+            return self.parse(s);
+        }
+        self.parse_with(s, &|symbol| (symbol == "%").then_some(Scale::percent_of(total)))
+    }
+
+    /// Looks up a unit symbol with the units and base this parser allows.
+    fn scale_from_symbol(&self, symbol: &str) -> Option<Scale> {
+        if let "b" | "bit" = symbol {
+            return self.bits.then_some(Scale::BIT);
+        }
+        let unit = unit_from_symbol(symbol)
+            .or_else(|| match self.decimal_suffix_base {
+                Base::Binary => unit_from_jedec_symbol(symbol),
+                Base::Decimal => None,
+            })
+            .or_else(|| self.lenient_units.then(|| unit_from_name(symbol)).flatten())
+            .or_else(|| self.aliases.then(|| unit_from_alias(symbol)).flatten())?;
+        if unit.is_bits() && !self.bits {
+            return None;
+        }
+        match self.decimal_suffix_base {
+            Base::Binary if !unit.is_bits() => Some(Scale::of(Units::binary()[unit.exponent() as usize])),
+            _ => Some(Scale::of(unit)),
+        }
+    }
+
+    fn parse_sum_with(&self, s: &str, lookup: &dyn Fn(&str) -> Option<Scale>) -> Result<Size, ParseSizeError> {
+        let skip_whitespace = |at: usize| s.len() - s[at..].trim_start().len();
+        let mut total = Size::ZERO;
+        let mut terms = 0;
+        let mut at = skip_whitespace(0);
+        while at < s.len() {
+            if terms > 0 && s[at..].starts_with('+') {
+                at = skip_whitespace(at + 1);
+            }
+            let length = term_length(&s[at..]);
+            if length == 0 {
+                return Err(ParseSizeError::InvalidNumber { at });
+            }
+            let term = self
+                .parse_with(&s[at..at + length], lookup)
+                .map_err(|err| err.offset_by(at))?;
+            total = total.checked_add(term).ok_or(ParseSizeError::Overflow)?;
+            terms += 1;
+            at = skip_whitespace(at + length);
+        }
+        if terms == 0 {
+            return Err(ParseSizeError::Empty);
+        }
+        Ok(total)
+    }
+
+    fn parse_with(&self, s: &str, lookup: &dyn Fn(&str) -> Option<Scale>) -> Result<Size, ParseSizeError> {
+        let start = s.len() - s.trim_start().len();
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseSizeError::Empty);
+        }
+        if s.starts_with('-') {
+            return Err(ParseSizeError::NegativeNotAllowed);
+        }
+        let (whole, fraction, symbol) = match radix_prefix(s) {
+            Some(_) if !self.radix => return Err(ParseSizeError::InvalidNumber { at: start + 1 }),
+            Some(radix) => {
+                let (digits, symbol) = self
+                    .split_radix_number(s, radix)
+                    .map_err(|at| ParseSizeError::InvalidNumber { at: start + at })?;
+                let whole = parse_digits(digits, radix).ok_or(ParseSizeError::Overflow)?;
+                (whole, "", symbol)
+            }
+            None => {
+                let number_end = number_end(s);
+                let (number, symbol) = s.split_at(number_end);
+                let symbol = symbol.trim_start();
+
+                let (whole, fraction) = self
+                    .split_number(number, !symbol.is_empty())
+                    .map_err(|at| ParseSizeError::InvalidNumber { at: start + at })?;
+                if whole.is_empty() && fraction.is_empty() {
+                    return Err(ParseSizeError::InvalidNumber { at: start });
+                }
+                let whole = parse_digits(whole, 10).ok_or(ParseSizeError::Overflow)?;
+                (whole, fraction, symbol)
+            }
+        };
+
+        let scale = match symbol {
+            "" => Scale::of(self.default_unit),
+            symbol => lookup(symbol).ok_or_else(|| ParseSizeError::UnknownUnit(symbol.to_owned()))?,
+        };
+
+        // Digits past the 19th can't change the result for any unit that fits in a
+        // u64, and dropping them keeps the scaled fraction within a u128.
+        let fraction = &fraction[..fraction.len().min(19)];
+        let (numerator, denominator) = match fraction.parse::<u128>() {
+            Ok(numerator) => (numerator, 10u128.pow(fraction.len() as u32)),
+            // The fraction is empty.
+            Err(_) => (0, 1),
+        };
+        let scaled = numerator * scale.per_unit;
+        let remainder = scaled % denominator;
+
+        let count = whole
+            .checked_mul(scale.per_unit)
+            .and_then(|count| count.checked_add(scaled / denominator))
+            .ok_or(ParseSizeError::Overflow)?;
+
+        let exact = remainder == 0 && count % scale.per_byte == 0;
+        if self.exact && !exact {
+            return Err(ParseSizeError::NotWholeBytes);
+        }
+        let bytes = if scale.round_up {
+            count / scale.per_byte + u128::from(!exact)
+        } else {
+            // Half a byte or more left over rounds up.
+            let left_over = count % scale.per_byte * denominator + remainder;
+            count / scale.per_byte + u128::from(left_over * 2 >= scale.per_byte * denominator)
+        };
+
+        Ok(Size(bytes.try_into().map_err(|_| ParseSizeError::Overflow)?))
+    }
+
+    /// Splits `s`, which starts with the prefix of a `radix` literal, into the
+    /// literal's digits and the unit after it, or returns the offset of the first
+    /// character that doesn't belong.
+    fn split_radix_number<'a>(&self, s: &'a str, radix: u32) -> Result<(&'a str, &'a str), usize> {
+        let body = &s[2..];
+        if !body.starts_with(|c: char| c.is_digit(radix)) {
+            return Err(2);
+        }
+        let end = body
+            .find(|c: char| !c.is_digit(radix) && c != '_')
+            .unwrap_or(body.len());
+        let (literal, symbol) = body.split_at(end);
+        if self.grouping == Grouping::None {
+            if let Some(at) = literal.find('_') {
+                return Err(2 + at);
+            }
+        }
+        // Literals are whole numbers.
+        if symbol.starts_with(['.', ',']) {
+            return Err(2 + end);
+        }
+        Ok((literal, symbol.trim_start()))
+    }
+
+    /// Splits `number` into its integer part, grouping separators included, and
+    /// the digits of its fraction, or returns the offset of the first character
+    /// that doesn't belong.
+    fn split_number<'a>(&self, number: &'a str, has_unit: bool) -> Result<(&'a str, &'a str), usize> {
+        let (integer, fraction, fraction_start) = match number.find('.') {
+            Some(dot) => (&number[..dot], &number[dot + 1..], dot + 1),
+            None => match self.find_decimal_comma(number, has_unit) {
+                Some(comma) => (&number[..comma], &number[comma + 1..], comma + 1),
+                None => (number, "", number.len()),
+            },
+        };
+        if let Some(at) = fraction.find(|c: char| !c.is_ascii_digit()) {
+            return Err(fraction_start + at);
+        }
+
+        match self.grouping {
+            Grouping::None => {
+                if let Some(at) = integer.find(is_group_separator) {
+                    return Err(at);
+                }
+            }
+            Grouping::Strict => {
+                check_groups(integer)?;
+                // A lone comma that could be a decimal one.
+                let mut commas = integer.match_indices(',');
+                if let (Some((at, _)), None) = (commas.next(), commas.next()) {
+                    if !has_unit && fraction_start == number.len() {
+                        return Err(at);
+                    }
+                }
+            }
+            Grouping::Lenient => {}
+        }
+
+        Ok((integer, fraction))
+    }
+
+    /// Returns where the comma is in `number` if it should be read as a decimal one.
+    fn find_decimal_comma(&self, number: &str, has_unit: bool) -> Option<usize> {
+        if !self.decimal_comma {
+            return None;
+        }
+        let comma = number.find(',')?;
+        if number[comma + 1..].contains(',') {
+            return None;
+        }
+        let grouping = match self.grouping {
+            Grouping::None => false,
+            _ => has_unit && check_groups(number).is_ok(),
+        };
+        (!grouping).then_some(comma)
+    }
+}
+
+/// Returns the radix of the literal `s` starts with, if its prefix is "0x", "0o"
+/// or "0b". As in Rust, prefixes are lowercase, so "0B" stays zero bytes, and
+/// "0b" is only binary when a binary digit follows, so "0b" on its own stays zero bits.
+fn radix_prefix(s: &str) -> Option<u32> {
+    match s.as_bytes() {
+        [b'0', b'x', ..] => Some(16),
+        [b'0', b'o', ..] => Some(8),
+        [b'0', b'b', b'0' | b'1', ..] => Some(2),
+        _ => None,
+    }
+}
+
+/// Reads the digits in `s` as a number in `radix`, skipping grouping separators,
+/// or returns `None` if it overflows.
+fn parse_digits(s: &str, radix: u32) -> Option<u128> {
+    s.chars()
+        .filter_map(|c| c.to_digit(radix))
+        .try_fold(0u128, |n, digit| n.checked_mul(radix.into())?.checked_add(digit.into()))
+}
+
+/// Whether `c` can split the integer part of a number into groups of digits.
+fn is_group_separator(c: char) -> bool {
+    matches!(c, ',' | '_' | '\u{2009}' | '\u{202F}')
+}
+
+/// Returns the length of the term at the start of a sum: a number and the unit
+/// after it, if there is one.
+fn term_length(s: &str) -> usize {
+    let number = match radix_prefix(s) {
+        Some(radix) => s[2..]
+            .find(|c: char| !c.is_digit(radix) && c != '_')
+            .map_or(s.len(), |end| 2 + end),
+        None => number_end(s),
+    };
+    let symbol_start = s.len() - s[number..].trim_start().len();
+    let rest = &s[symbol_start..];
+    if number > 0 && (rest.is_empty() || rest.starts_with(|c: char| c.is_ascii_digit() || c == '+')) {
+        return number;
+    }
+    let symbol_length = rest.find(|c: char| c.is_whitespace() || c == '+').unwrap_or(rest.len());
+    symbol_start + symbol_length
+}
+
+/// Returns the length of the unit symbol at the start of `s`, along with any
+/// whitespace before it, or 0 if the letters there aren't a symbol `lookup` knows.
+fn symbol_length(s: &str, lookup: &dyn Fn(&str) -> Option<Scale>) -> usize {
+    let symbol = s.trim_start();
+    let length = letters_length(symbol);
+    match lookup(&symbol[..length]) {
+        Some(_) if length > 0 => s.len() - symbol.len() + length,
+        _ => 0,
+    }
+}
+
+/// Returns the length of the run of ASCII letters at the start of `s`.
+fn letters_length(s: &str) -> usize {
+    s.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(s.len())
+}
+
+/// Returns the length of the number at the start of `s`: its digits, decimal
+/// points, and any grouping separators between two digits.
+fn number_end(s: &str) -> usize {
+    let mut end = 0;
+    let mut previous = None;
+    let mut chars = s.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let between_digits = previous.is_some_and(|p: char| p.is_ascii_digit())
+            && chars.peek().is_some_and(|&(_, next)| next.is_ascii_digit());
+        if !(c.is_ascii_digit() || c == '.' || (is_group_separator(c) && between_digits)) {
+            break;
+        }
+        end = i + c.len_utf8();
+        previous = Some(c);
+    }
+    end
+}
+
+/// Checks that any separators in `integer` split it into groups of three digits
+/// after a first group of one to three, all with the same separator. Returns the
+/// offset of the first separator that doesn't fit.
+fn check_groups(integer: &str) -> Result<(), usize> {
+    let mut separator = None;
+    let mut last = 0;
+    let mut group = 0;
+    for (i, c) in integer.char_indices() {
+        if c.is_ascii_digit() {
+            group += 1;
+            continue;
+        }
+        let fits = match separator {
+            None => (1..=3).contains(&group),
+            Some(separator) => separator == c && group == 3,
+        };
+        if !fits {
+            return Err(if separator.is_some() && group != 3 { last } else { i });
+        }
+        separator = Some(c);
+        last = i;
+        group = 0;
+    }
+    if separator.is_some() && group != 3 {
+        return Err(last);
+    }
+    Ok(())
+}
+
+/// Parses an unsigned amount such as `"54.2 kB"`, `"100 Mbit"` or `"22000"`.
+///
+/// The number may have a fractional part and is scaled by the unit using
+/// integer math. A fraction of a byte is rounded to the nearest whole byte, or
+/// up for amounts in bits. It can be followed by the unit directly or after any
+/// whitespace, including the no-break spaces `Separator` can print.
+pub(crate) fn parse_size(s: &str) -> Result<Size, ParseSizeError> {
+    SizeParser::new().parse(s)
+}
+
+/// Like `parse_size`, but fails with `NotWholeBytes` rather than rounding.
+pub(crate) fn parse_size_strict(s: &str) -> Result<Size, ParseSizeError> {
+    SizeParser::new().exact(true).parse(s)
+}
+
+/// Like `parse_size`, but for a command-line argument that may not be valid Unicode.
+pub(crate) fn parse_size_os(s: &OsStr) -> Result<Size, ParseSizeError> {
+    match std::str::from_utf8(s.as_encoded_bytes()) {
+        Ok(s) => parse_size(s),
+        Err(err) => Err(ParseSizeError::NotUnicode { at: err.valid_up_to() }),
+    }
+}
+
+/// Like `parse_size`, but reads units like `Units::parse_lenient`.
+pub(crate) fn parse_size_lenient(s: &str) -> Result<Size, ParseSizeError> {
+    SizeParser::new().lenient_units(true).allow_aliases(true).parse(s)
+}
+
+/// Like `parse_size`, but with JEDEC symbols, where "KB", "MB", ... are powers of 1024.
+pub(crate) fn parse_size_jedec(s: &str) -> Result<Size, ParseSizeError> {
+    SizeParser::new().decimal_suffix_base(Base::Binary).parse(s)
+}
+
+/// Like `parse_size`, but with the suffixes `dd` and `truncate` accept.
+pub(crate) fn parse_size_cli(s: &str) -> Result<Size, ParseSizeError> {
+    SizeParser::new().parse_with(s, &|symbol| unit_from_cli_symbol(symbol).map(Scale::of))
+}
+
+/// Parses a size the way the Linux kernel's `memparse` does: a whole number in
+/// C notation, then an optional binary suffix that shifts it left by 10 bits a step.
+pub(crate) fn parse_memparse(s: &str) -> Result<Size, ParseSizeError> {
+    if s.is_empty() {
+        return Err(ParseSizeError::Empty);
+    }
+    if s.starts_with('-') {
+        return Err(ParseSizeError::NegativeNotAllowed);
+    }
+
+    // Like `strtoull` with base 0, "0x" only starts a hex number if a hex digit
+    // follows it. Otherwise the leading zero is an octal number on its own.
+    let (radix, start) = match s.as_bytes() {
+        [b'0', b'x' | b'X', digit, ..] if digit.is_ascii_hexdigit() => (16, 2),
+        [b'0', ..] => (8, 0),
+        _ => (10, 0),
+    };
+    let end = s[start..]
+        .find(|c: char| !c.is_digit(radix))
+        .map_or(s.len(), |i| start + i);
+    if end == start {
+        return Err(ParseSizeError::InvalidNumber { at: 0 });
+    }
+    let number = u64::from_str_radix(&s[start..end], radix).map_err(|_| ParseSizeError::Overflow)?;
+
+    let shift = match &s[end..] {
+        "" => 0,
+        "K" | "k" => 10,
+        "M" | "m" => 20,
+        "G" | "g" => 30,
+        "T" | "t" => 40,
+        "P" | "p" => 50,
+        "E" | "e" => 60,
+        rest if rest.starts_with(|c: char| c.is_ascii_digit() || c == '.') => {
+            return Err(ParseSizeError::InvalidNumber { at: end })
+        }
+        suffix => return Err(ParseSizeError::UnknownUnit(suffix.to_owned())),
+    };
+    number
+        .checked_mul(1 << shift)
+        .map(Size)
+        .ok_or(ParseSizeError::Overflow)
+}
@@ -0,0 +1,101 @@
+//! Deserializes a `Size` from whatever a producer sends: a number of bytes, as an
+//! integer or a float, or a string such as "1 MiB".
+//!
+//! Use it with `#[serde(with = "repr_size::serde_flexible")]` to serialize sizes
+//! as a bare `u64`, like the default impl, or with
+//! `#[serde(with = "repr_size::serde_flexible::string")]` to serialize them as
+//! strings, like `serde_string`.
+//!
+//! Strings are read like `FromStr` reads them. With the `float` feature, floats
+//! are read like `Size::try_from_f64` reads them, rounding half to even;
+//! without it, they are rejected. Negative numbers and strings that don't parse
+//! fail with the message of the `SizeConversionError`, `FloatSizeError` or
+//! `ParseSizeError`.
+//!
+//! # Usage
+//! ```rust
+//! # use repr_size::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Upload {
+//!     #[serde(with = "repr_size::serde_flexible")]
+//!     size: Size,
+//! }
+//!
+//! for json in [r#"{ "size": 1048576 }"#, r#"{ "size": "1 MiB" }"#] {
+//!     let upload: Upload = serde_json::from_str(json).unwrap();
+//!     assert_eq!(upload.size, Size::MIB);
+//!     assert_eq!(serde_json::to_string(&upload).unwrap(), r#"{"size":1048576}"#);
+//! }
+//! # #[cfg(feature = "float")]
+//! assert_eq!(serde_json::from_str::<Upload>(r#"{ "size": 1.5e9 }"#).unwrap().size, Size::from(1_500_000_000u64));
+//! # #[cfg(feature = "float")]
+//! # assert_eq!(serde_json::from_str::<Upload>(r#"{ "size": 1048576.0 }"#).unwrap().size, Size::MIB);
+//!
+//! let err = |json: &str| serde_json::from_str::<Upload>(json).unwrap_err().to_string();
+//! assert!(err(r#"{ "size": -1 }"#).starts_with("byte sizes cannot be negative, got -1"));
+//! assert!(err(r#"{ "size": "1 MiBs" }"#).starts_with("unknown unit `MiBs`, did you mean `MiB`?"));
+//! # #[cfg(feature = "float")]
+//! # {
+//! #     assert!(err(r#"{ "size": -1.5 }"#).starts_with("byte sizes cannot be negative, got -1.5"));
+//! #     assert!(err(r#"{ "size": 1e20 }"#).starts_with("100000000000000000000 bytes is larger than the maximum size"));
+//! #     assert_eq!(serde_json::from_str::<Upload>(r#"{ "size": 2.5 }"#).unwrap().size, Size::from(2u64));
+//! #     assert_eq!(serde_json::from_str::<Upload>(r#"{ "size": 3.5 }"#).unwrap().size, Size::from(4u64));
+//! #     assert_eq!(serde_json::from_str::<Upload>(r#"{ "size": -0.0 }"#).unwrap().size, Size::ZERO);
+//! #     assert!(err(r#"{ "size": 18446744073709551616 }"#).starts_with("18446744073709552000 bytes is larger than the maximum size"));
+//! # }
+//! # #[cfg(not(feature = "float"))]
+//! # assert!(err(r#"{ "size": 1.5e9 }"#).starts_with("invalid type: floating point `1500000000.0`, expected a size"));
+//! # assert!(err(r#"{ "size": "" }"#).starts_with("cannot parse a size from an empty string"));
+//! # assert!(err(r#"{ "size": "-1 MiB" }"#).starts_with("sizes cannot be negative"));
+//! # assert!(err(r#"{ "size": "17 EiB" }"#).starts_with("size is larger than the maximum"));
+//! # assert!(err(r#"{ "size": null }"#).starts_with("invalid type: null, expected a size"));
+//! # assert!(err(r#"{ "size": true }"#).starts_with("invalid type: boolean `true`, expected a size"));
+//! # assert!(err(r#"{ "size": [1] }"#).starts_with("invalid type: sequence, expected a size"));
+//! # assert_eq!(serde_json::from_str::<Upload>(r#"{ "size": 18446744073709551615 }"#).unwrap().size, Size::MAX);
+//! # assert_eq!(serde_json::from_str::<Upload>(r#"{ "size": "0x10 MiB" }"#).unwrap().size, Size::MIB * 16);
+//! ```
+//!
+//! Serializing as strings instead:
+//! ```rust
+//! # use repr_size::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Upload {
+//!     #[serde(with = "repr_size::serde_flexible::string")]
+//!     size: Size,
+//! }
+//!
+//! let upload: Upload = serde_json::from_str(r#"{ "size": 1048576 }"#).unwrap();
+//! assert_eq!(serde_json::to_string(&upload).unwrap(), r#"{"size":"1 MiB"}"#);
+//! # assert_eq!(serde_json::from_str::<Upload>(r#"{ "size": "1 MiB" }"#).unwrap(), upload);
+//! # #[cfg(feature = "float")]
+//! # assert_eq!(serde_json::from_str::<Upload>(r#"{ "size": 1048576.0 }"#).unwrap(), upload);
+//! # for size in [Size::ZERO, Size::from(54_222u64), Size::MB * 3, Size::MAX] {
+//! #     let upload = Upload { size };
+//! #     assert_eq!(serde_json::from_str::<Upload>(&serde_json::to_string(&upload).unwrap()).unwrap(), upload);
+//! # }
+//! ```
+
+use serde::{Deserializer, Serialize, Serializer};
+
+use crate::serde_string::SizeVisitor;
+use crate::Size;
+
+/// Serializes `size` as a bare `u64`, like the default impl.
+pub fn serialize<S: Serializer>(size: &Size, serializer: S) -> Result<S::Ok, S::Error> {
+    size.serialize(serializer)
+}
+
+/// Deserializes a size from an integer, a float or a string.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Size, D::Error> {
+    deserializer.deserialize_any(SizeVisitor { floats: true })
+}
+
+/// Like `serde_flexible`, but serializes sizes as strings like `serde_string` does.
+pub mod string {
+    pub use super::deserialize;
+    pub use crate::serde_string::serialize;
+}
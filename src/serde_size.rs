@@ -0,0 +1,53 @@
+use std::fmt::{self, Formatter};
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::Size;
+
+/// Writes the byte count as a `u64` on every platform.
+impl Serialize for Size {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+/// Reads a byte count written as any integer that fits in a `u64`.
+impl<'de> Deserialize<'de> for Size {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_u64(BytesVisitor)
+    }
+}
+
+struct BytesVisitor;
+
+impl Visitor<'_> for BytesVisitor {
+    type Value = Size;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a number of bytes from 0 to {}", u64::MAX)
+    }
+
+    fn visit_u64<E: de::Error>(self, bytes: u64) -> Result<Size, E> {
+        Ok(Size(bytes))
+    }
+
+    fn visit_i64<E: de::Error>(self, bytes: i64) -> Result<Size, E> {
+        Size::try_from(bytes).map_err(E::custom)
+    }
+
+    fn visit_u128<E: de::Error>(self, bytes: u128) -> Result<Size, E> {
+        match u64::try_from(bytes) {
+            Ok(bytes) => Ok(Size(bytes)),
+            Err(_) => Err(E::custom(format_args!(
+                "{} bytes is larger than the maximum size of {} bytes",
+                bytes,
+                u64::MAX
+            ))),
+        }
+    }
+
+    fn visit_i128<E: de::Error>(self, bytes: i128) -> Result<Size, E> {
+        Size::try_from(bytes).map_err(E::custom)
+    }
+}
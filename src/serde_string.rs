@@ -0,0 +1,136 @@
+//! (De)serializes a `Size` as a string such as "25 MiB", for config files.
+//!
+//! Use it with `#[serde(with = "repr_size::serde_string")]`, or
+//! `repr_size::serde_string_option` for an `Option<Size>`. Without either, a
+//! `Size` is (de)serialized as a bare `u64`.
+//!
+//! Sizes are written exactly, in the largest decimal or binary unit that divides
+//! them, so they read back to the same size. Strings are read like `FromStr`
+//! reads them, and bare integers are read as a number of bytes, so existing
+//! files keep working. A string that doesn't parse fails with the
+//! `ParseSizeError` message.
+//!
+//! # Usage
+//! ```rust
+//! # use repr_size::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "repr_size::serde_string")]
+//!     max_upload: Size,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{ "max_upload": "25 MiB" }"#).unwrap();
+//! assert_eq!(config.max_upload, Size::MIB * 25);
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"max_upload":"25 MiB"}"#);
+//!
+//! let config: Config = serde_json::from_str(r#"{ "max_upload": 1500 }"#).unwrap();
+//! assert_eq!(config.max_upload, Size::from(1500u64));
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"max_upload":"1500 B"}"#);
+//!
+//! let err = serde_json::from_str::<Config>(r#"{ "max_upload": "25 MiBs" }"#).unwrap_err();
+//! assert!(err.to_string().starts_with("unknown unit `MiBs`, did you mean `MiB`?"));
+//! # for (size, written) in [
+//! #     (Size::ZERO, "0 B"),
+//! #     (Size::from(54_222u64), "54222 B"),
+//! #     (Size::KB * 54, "54 kB"),
+//! #     (Size::MB, "1 MB"),
+//! #     (Size::MIB * 1536, "1536 MiB"),
+//! #     (Size::GIB * 3, "3 GiB"),
+//! #     (Size::MAX, "18446744073709551615 B"),
+//! #     (Size::EIB * 15, "15 EiB"),
+//! #     (Size::EB * 18, "18 EB"),
+//! # ] {
+//! #     let config = Config { max_upload: size };
+//! #     let json = serde_json::to_string(&config).unwrap();
+//! #     assert_eq!(json, format!(r#"{{"max_upload":"{}"}}"#, written));
+//! #     assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+//! # }
+//! # let err = |json: &str| serde_json::from_str::<Config>(json).unwrap_err().to_string();
+//! # assert!(err(r#"{ "max_upload": -1 }"#).starts_with("byte sizes cannot be negative, got -1"));
+//! # assert!(err(r#"{ "max_upload": "" }"#).starts_with("cannot parse a size from an empty string"));
+//! # assert!(err(r#"{ "max_upload": "17 EiB" }"#).starts_with("size is larger than the maximum"));
+//! # assert!(err(r#"{ "max_upload": 1.5 }"#).starts_with("invalid type: floating point `1.5`, expected a size"));
+//! # assert!(err(r#"{ "max_upload": null }"#).starts_with("invalid type: null, expected a size"));
+//! ```
+
+use std::fmt::{self, Formatter};
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+
+use crate::{Base, Size, Units};
+
+/// Serializes `size` as a string, exactly, in the largest unit that divides it.
+pub fn serialize<S: Serializer>(size: &Size, serializer: S) -> Result<S::Ok, S::Error> {
+    let unit = exact_unit(*size);
+    serializer.collect_str(&format_args!("{} {}", size.bytes() / unit.bytes(), unit))
+}
+
+/// Returns the largest decimal or binary byte unit that divides `size` exactly.
+pub(crate) fn exact_unit(size: Size) -> Units {
+    let decimal = size.best_exact_unit(Base::Decimal);
+    let binary = size.best_exact_unit(Base::Binary);
+    if binary.bytes() > decimal.bytes() {
+        binary
+    } else {
+        decimal
+    }
+}
+
+/// Deserializes a size from a string like `FromStr` does, or from a number of bytes.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Size, D::Error> {
+    deserializer.deserialize_any(SizeVisitor { floats: false })
+}
+
+/// Reads a size from a string or a number of bytes.
+pub(crate) struct SizeVisitor {
+    /// Whether a float number of bytes is read like `Size::try_from_f64` does.
+    /// Without the `float` feature, floats are always rejected.
+    #[cfg_attr(not(feature = "float"), allow(dead_code))]
+    pub(crate) floats: bool,
+}
+
+impl Visitor<'_> for SizeVisitor {
+    type Value = Size;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a size such as \"25 MiB\", or a number of bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Size, E> {
+        s.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, bytes: u64) -> Result<Size, E> {
+        Ok(Size::from(bytes))
+    }
+
+    fn visit_i64<E: de::Error>(self, bytes: i64) -> Result<Size, E> {
+        Size::try_from(bytes).map_err(E::custom)
+    }
+
+    #[cfg(feature = "float")]
+    fn visit_f64<E: de::Error>(self, bytes: f64) -> Result<Size, E> {
+        if !self.floats {
+            return Err(E::invalid_type(de::Unexpected::Float(bytes), &self));
+        }
+        Size::try_from_f64(bytes).map_err(E::custom)
+    }
+}
+
+/// A `Size` that (de)serializes through this module, for `serde_string_option`.
+pub(crate) struct AsString(pub(crate) Size);
+
+impl serde::Serialize for AsString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for AsString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(AsString)
+    }
+}
@@ -0,0 +1,51 @@
+//! (De)serializes an `Option<Size>` like `serde_string` does a `Size`, since
+//! `#[serde(with)]` doesn't reach inside an `Option`.
+//!
+//! `None` is written as null. Add `#[serde(default)]` to read a missing field as
+//! `None` too.
+//!
+//! # Usage
+//! ```rust
+//! # use repr_size::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "repr_size::serde_string_option", default)]
+//!     quota: Option<Size>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{ "quota": "2 GiB" }"#).unwrap();
+//! assert_eq!(config.quota, Some(Size::GIB * 2));
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"quota":"2 GiB"}"#);
+//!
+//! let config: Config = serde_json::from_str("{}").unwrap();
+//! assert_eq!(config.quota, None);
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"quota":null}"#);
+//! # assert_eq!(serde_json::from_str::<Config>(r#"{ "quota": null }"#).unwrap().quota, None);
+//! # assert_eq!(serde_json::from_str::<Config>(r#"{ "quota": 1024 }"#).unwrap().quota, Some(Size::KIB));
+//! # for quota in [None, Some(Size::ZERO), Some(Size::from(54_222u64)), Some(Size::MB * 3), Some(Size::MAX)] {
+//! #     let config = Config { quota };
+//! #     assert_eq!(serde_json::from_str::<Config>(&serde_json::to_string(&config).unwrap()).unwrap(), config);
+//! # }
+//! # let err = serde_json::from_str::<Config>(r#"{ "quota": "2 GiBs" }"#).unwrap_err();
+//! # assert!(err.to_string().starts_with("unknown unit `GiBs`, did you mean `GiB`?"));
+//! ```
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::serde_string::AsString;
+use crate::Size;
+
+/// Serializes `size` like `serde_string::serialize`, or as null.
+pub fn serialize<S: Serializer>(size: &Option<Size>, serializer: S) -> Result<S::Ok, S::Error> {
+    match size {
+        Some(size) => serializer.serialize_some(&AsString(*size)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Deserializes a size like `serde_string::deserialize`, or null as `None`.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Size>, D::Error> {
+    Ok(Option::<AsString>::deserialize(deserializer)?.map(|size| size.0))
+}
@@ -0,0 +1,157 @@
+//! (De)serializes a `Size` as an object with an explicit unit, such as
+//! `{ "value": 1536, "unit": "MiB" }`, for clients that don't share a convention
+//! for what a bare number means.
+//!
+//! Use it with `#[serde(with = "repr_size::serde_struct")]`.
+//!
+//! Sizes are written exactly, as a whole `value` in the largest decimal or binary
+//! unit that divides them, falling back to bytes. Reading accepts any whole or
+//! fractional `value` with any unit symbol `FromStr` for `Units` knows, such as
+//! `{ "value": 1.5, "unit": "GiB" }`. Fractions are read exactly and rounded like
+//! `FromStr` for `Size` rounds them, and the result is checked for overflow.
+//! Unknown units, negative values and sizes that are too large fail with the
+//! `ParseSizeError` message.
+//!
+//! # Usage
+//! ```rust
+//! # use repr_size::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Volume {
+//!     #[serde(with = "repr_size::serde_struct")]
+//!     capacity: Size,
+//! }
+//!
+//! let volume = Volume { capacity: Size::from_units(1536, Units::Mebibytes) };
+//! let json = serde_json::to_string(&volume).unwrap();
+//! assert_eq!(json, r#"{"capacity":{"value":1536,"unit":"MiB"}}"#);
+//! assert_eq!(serde_json::from_str::<Volume>(&json).unwrap(), volume);
+//!
+//! // No unit but bytes divides 54,222 bytes exactly.
+//! let volume = Volume { capacity: Size::from(54_222u64) };
+//! let json = serde_json::to_string(&volume).unwrap();
+//! assert_eq!(json, r#"{"capacity":{"value":54222,"unit":"B"}}"#);
+//! assert_eq!(serde_json::from_str::<Volume>(&json).unwrap(), volume);
+//!
+//! let volume: Volume = serde_json::from_str(r#"{ "capacity": { "value": 1.5, "unit": "GiB" } }"#).unwrap();
+//! assert_eq!(volume.capacity, Size::from_units(1536, Units::Mebibytes));
+//!
+//! let err = |json: &str| serde_json::from_str::<Volume>(json).unwrap_err().to_string();
+//! assert!(err(r#"{ "capacity": { "value": 2, "unit": "GiBs" } }"#).starts_with("unknown unit `GiBs`, did you mean `GiB`?"));
+//! assert!(err(r#"{ "capacity": { "value": -2, "unit": "GiB" } }"#).starts_with("sizes cannot be negative"));
+//! # for (size, written) in [
+//! #     (Size::ZERO, r#"{"value":0,"unit":"B"}"#),
+//! #     (Size::KB * 54, r#"{"value":54,"unit":"kB"}"#),
+//! #     (Size::GIB * 3, r#"{"value":3,"unit":"GiB"}"#),
+//! #     (Size::EIB * 15, r#"{"value":15,"unit":"EiB"}"#),
+//! #     (Size::MAX, r#"{"value":18446744073709551615,"unit":"B"}"#),
+//! # ] {
+//! #     let volume = Volume { capacity: size };
+//! #     let json = serde_json::to_string(&volume).unwrap();
+//! #     assert_eq!(json, format!(r#"{{"capacity":{}}}"#, written));
+//! #     assert_eq!(serde_json::from_str::<Volume>(&json).unwrap(), volume);
+//! # }
+//! # let read = |json: &str| serde_json::from_str::<Volume>(&format!(r#"{{ "capacity": {} }}"#, json)).map(|volume| volume.capacity).map_err(|err| err.to_string());
+//! # assert_eq!(read(r#"{ "unit": "kB", "value": 2 }"#), Ok(Size::KB * 2));
+//! # assert_eq!(read(r#"{ "value": 100, "unit": "Mbit" }"#), Ok(Size::from(12_500_000u64)));
+//! # assert_eq!(read(r#"{ "value": 0.5, "unit": "kbit" }"#), Ok(Size::from(63u64)));
+//! # assert_eq!(read(r#"{ "value": 0.1, "unit": "kB" }"#), Ok(Size::from(100u64)));
+//! # assert_eq!(read(r#"{ "value": 1.0005, "unit": "kB" }"#), Ok(Size::from(1001u64)));
+//! # assert_eq!(read(r#"{ "value": 1e3, "unit": "B" }"#), Ok(Size::KB));
+//! # assert!(read(r#"{ "value": 16, "unit": "EiB" }"#).unwrap_err().starts_with(&ParseSizeError::Overflow.to_string()));
+//! # assert!(read(r#"{ "value": 18446744073709551615, "unit": "kbit" }"#).unwrap_err().starts_with("size is larger than the maximum"));
+//! # assert!(read(r#"{ "value": 16.0, "unit": "EiB" }"#).unwrap_err().starts_with("size is larger than the maximum"));
+//! # assert!(read(r#"{ "value": 1e300, "unit": "B" }"#).unwrap_err().starts_with("size is larger than the maximum"));
+//! # assert!(read(r#"{ "value": -0.5, "unit": "B" }"#).unwrap_err().starts_with("sizes cannot be negative"));
+//! # assert!(read(r#"{ "value": 1, "unit": "" }"#).unwrap_err().starts_with("cannot parse a size from an empty string"));
+//! # assert!(read(r#"{ "value": 1, "unit": "parsecs" }"#).unwrap_err().starts_with("unknown unit `parsecs`, expected one of B,"));
+//! # assert!(read(r#"{ "value": "1", "unit": "B" }"#).unwrap_err().starts_with("invalid type: string \"1\", expected a number"));
+//! # assert!(read(r#"{ "value": 1 }"#).unwrap_err().starts_with("missing field `unit`"));
+//! # assert!(read(r#"{ "unit": "B" }"#).unwrap_err().starts_with("missing field `value`"));
+//! # assert!(read(r#""1 kB""#).unwrap_err().starts_with("invalid type: string \"1 kB\", expected struct"));
+//! ```
+
+use std::borrow::Cow;
+use std::fmt::{self, Formatter};
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+
+use crate::serde_string::exact_unit;
+use crate::{ParseSizeError, Size, Units};
+
+#[derive(Serialize)]
+struct Written {
+    value: u64,
+    unit: &'static str,
+}
+
+#[derive(Deserialize)]
+struct Read<'a> {
+    value: Amount,
+    #[serde(borrow)]
+    unit: Cow<'a, str>,
+}
+
+/// The `value` of a size, as whichever kind of number it was written as.
+enum Amount {
+    Whole(u64),
+    Negative,
+    Fraction(f64),
+}
+
+/// Serializes `size` as a whole `value` in the largest unit that divides it.
+pub fn serialize<S: Serializer>(size: &Size, serializer: S) -> Result<S::Ok, S::Error> {
+    let unit = exact_unit(*size);
+    Written {
+        value: size.bytes() / unit.bytes(),
+        unit: unit.symbol(),
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes a size from a `value` and a `unit`.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Size, D::Error> {
+    let read = Read::deserialize(deserializer)?;
+    let unit: Units = read.unit.parse().map_err(de::Error::custom)?;
+    let size = match read.value {
+        Amount::Whole(value) => value
+            .checked_mul(unit.bytes())
+            .map(Size::from)
+            .ok_or(ParseSizeError::Overflow),
+        Amount::Negative => Err(ParseSizeError::NegativeNotAllowed),
+        // The shortest decimal that reads back as the same float, parsed exactly.
+        Amount::Fraction(value) => format!("{} {}", value, unit).parse(),
+    };
+    size.map_err(de::Error::custom)
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AmountVisitor)
+    }
+}
+
+struct AmountVisitor;
+
+impl Visitor<'_> for AmountVisitor {
+    type Value = Amount;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a number")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Amount, E> {
+        Ok(Amount::Whole(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Amount, E> {
+        Ok(u64::try_from(value).map_or(Amount::Negative, Amount::Whole))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Amount, E> {
+        Ok(Amount::Fraction(value))
+    }
+}
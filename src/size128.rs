@@ -0,0 +1,228 @@
+use std::fmt::Display;
+use std::num::TryFromIntError;
+
+use crate::format::{pad, promotion, Scaled};
+use crate::{
+    binary_units_for, decimal_units_for, Base, Size, Units, BINARY_UNITS, DECIMAL_UNITS, DEFAULT_BASE,
+};
+
+/// Represents an amount of bytes too large for `Size`, such as totals summed
+/// across many petabyte-scale volumes.
+///
+/// Offers the same unit selection and formatting as `Size`. Convert from a
+/// `Size` losslessly with `From`, and back with `TryFrom`, which fails if the
+/// value does not fit in a `u64`.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let bucket = Size::from_units(10_000, Units::Petabytes);
+/// let total = Size128::from(bucket) + Size128::from(bucket);
+///
+/// assert_eq!(total.get_units(), Units::Exabytes);
+/// println!("{}", total); // "20.0 EB"
+/// # assert_eq!(total.to_decimal_string(), "20.0 EB");
+/// assert!(Size::try_from(total).is_err());
+/// # assert_eq!(Size::try_from(Size128::from(bucket)), Ok(bucket));
+/// # assert_eq!(Size128::from(u128::MAX).to_binary_string(), "295147905179352825856.0 EiB");
+/// # assert_eq!(Size128::from(1u128 << 70).repr(Units::Pebibytes), "1048576.0 PiB");
+/// # assert_eq!(Size128::from_units(3, Units::Kibibytes), Size128::from(3072u128));
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, PartialOrd, Eq, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "u128", into = "u128"))]
+pub struct Size128(u128);
+
+/// Prints the size like `Display` for `Size` does, taking the number of decimals,
+/// padding, the `+` flag and the alternate flag `{:#}` for binary units from the
+/// format string.
+///
+/// ```rust
+/// # use repr_size::*;
+/// # if cfg!(feature = "default-binary") { return; }
+/// let size = Size128::from(54_222u128);
+///
+/// assert_eq!(format!("[{:>12.2}]", size), "[    54.22 kB]");
+/// assert_eq!(format!("{:#}", size), "53.0 KiB");
+/// assert_eq!(format!("[{:<+8.0}]", size), "[+54 kB  ]");
+/// # assert_eq!(format!("{:#.3}", size), "52.951 KiB");
+/// # assert_eq!(format!("[{:^9}]", Size128::from(0u128)), "[   0 B   ]");
+/// # assert_eq!(format!("{:.2}", Size128::from(999u128)), "999 B");
+/// # assert_eq!(format!("{}", Size128::from(999_960u128)), "1.0 MB");
+/// # assert_eq!(format!("{:.0}", Size128::from(u128::MAX)), "340282366920938463463 EB");
+/// # for bytes in [0u64, 1, 999, 1000, 1023, 1024, 999_960, 1_048_570, u64::MAX] {
+/// #     for precision in 0..4 {
+/// #         assert_eq!(format!("{:.*}", precision, Size128::from(bytes)), format!("{:.*}", precision, Size::from(bytes)));
+/// #         assert_eq!(format!("{:#.*}", precision, Size128::from(bytes)), format!("{:#.*}", precision, Size::from(bytes)));
+/// #     }
+/// # }
+/// ```
+impl Display for Size128 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if f.sign_plus() { "+" } else { "" };
+        let base = if f.alternate() { Base::Binary } else { DEFAULT_BASE };
+        let (units, unit) = match base {
+            Base::Decimal => (DECIMAL_UNITS, self.get_decimal_unit()),
+            Base::Binary => (BINARY_UNITS, self.get_binary_unit()),
+        };
+        let decimals = f.precision().unwrap_or(1);
+        let amount = Scaled::new(self.0, unit, decimals);
+        let (unit, amount) = match promotion(units, unit, &amount) {
+            Some(larger) => (larger, Scaled::new(self.0, larger, decimals)),
+            None => (unit, amount),
+        };
+        pad(f, format_args!("{}{} {}", sign, amount, unit))
+    }
+}
+
+impl Size128 {
+    /// Gets the largest decimal unit that can represent this number without all significant digits being in the decimal.
+    pub fn get_units(&self) -> Units {
+        decimal_units_for(self.0)
+    }
+
+    /// Gets the largest decimal unit (kB, MB, ...) that can represent this number
+    /// without all significant digits being in the decimal.
+    ///
+    /// Sizes past an exabyte stay in exabytes, as there's no larger unit.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size128::from(999_999u128).get_decimal_unit(), Units::Kilobytes);
+    /// assert_eq!(Size128::from(10u128.pow(24)).get_decimal_unit(), Units::Exabytes);
+    /// # assert_eq!(Size128::from(u128::MAX).get_decimal_unit(), Units::Exabytes);
+    /// # assert_eq!(Size128::from(u128::MAX).get_binary_unit(), Units::Exbibytes);
+    /// # assert_eq!(Size128::from(0u128).get_binary_unit(), Units::Bytes);
+    /// # assert_eq!(Size128::from(999u128).get_decimal_unit(), Units::Bytes);
+    /// # assert_eq!(Size128::from(1000u128).get_decimal_unit(), Units::Kilobytes);
+    /// # assert_eq!(Size128::from(1u128 << 64).get_binary_unit(), Units::Exbibytes);
+    /// ```
+    pub fn get_decimal_unit(&self) -> Units {
+        decimal_units_for(self.0)
+    }
+
+    /// Gets the largest binary unit (KiB, MiB, ...) that can represent this number
+    /// without all significant digits being in the decimal.
+    pub fn get_binary_unit(&self) -> Units {
+        binary_units_for(self.0)
+    }
+
+    /// Gets the largest binary unit that can represent this number without all
+    /// significant digits being in the decimal.
+    #[deprecated(note = "SI units are the decimal ones; use `get_binary_unit` for the same result")]
+    pub fn get_si_units(&self) -> Units {
+        self.get_binary_unit()
+    }
+
+    /// Returns the size represented as an amount and a decimal unit (kB, MB, ...).
+    pub fn to_decimal_string(&self) -> String {
+        self.repr_auto(DECIMAL_UNITS, self.get_decimal_unit())
+    }
+
+    /// Returns the size represented as an amount and a binary unit (KiB, MiB, ...).
+    pub fn to_binary_string(&self) -> String {
+        self.repr_auto(BINARY_UNITS, self.get_binary_unit())
+    }
+
+    /// Returns the size represented as an amount and a binary unit.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # #![allow(deprecated)]
+    /// # use repr_size::*;
+    /// assert_eq!(Size128::from(54_222u128).to_si_string(), "53.0 KiB");
+    /// # for bytes in [0u128, 1, 1023, 1024, 54_222, u64::MAX as u128, u128::MAX] {
+    /// #     let size = Size128::from(bytes);
+    /// #     assert_eq!(size.to_si_string(), size.to_binary_string());
+    /// #     assert_eq!(size.get_si_units(), size.get_binary_unit());
+    /// #     if !cfg!(feature = "default-binary") {
+    /// #         assert_eq!(size.to_decimal_string(), size.to_string());
+    /// #     }
+    /// #     assert_eq!(size.get_decimal_unit(), size.get_units());
+    /// # }
+    /// ```
+    #[deprecated(note = "SI units are the decimal ones; use `to_binary_string` for the same result")]
+    pub fn to_si_string(&self) -> String {
+        self.to_binary_string()
+    }
+
+    /// Returns a string representation of the size using
+    /// the given unit of bytes. Zero is written as "0 B" whatever the unit.
+    pub fn repr(&self, unit: Units) -> String {
+        if self.0 == 0 {
+            return String::from("0 B");
+        }
+        format!("{} {}", Scaled::new(self.0, unit, 1), unit)
+    }
+
+    /// Like `repr`, but moves up to the next of `units` if rounding reaches it.
+    fn repr_auto(&self, units: &[Units], unit: Units) -> String {
+        let amount = Scaled::new(self.0, unit, 1);
+        match promotion(units, unit, &amount) {
+            Some(larger) => self.repr(larger),
+            None => format!("{} {}", amount, unit),
+        }
+    }
+
+    /// Returns a Size128 derived from unit's byte amount times the number given.
+    pub fn from_units(x: u128, unit: Units) -> Size128 {
+        Self(x * unit.bytes() as u128)
+    }
+}
+
+impl std::ops::Add for Size128 {
+    type Output = Size128;
+
+    fn add(self, rhs: Size128) -> Size128 {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl From<u128> for Size128 {
+    fn from(rhs: u128) -> Self {
+        Self(rhs)
+    }
+}
+
+impl From<u64> for Size128 {
+    fn from(rhs: u64) -> Self {
+        Self(rhs as u128)
+    }
+}
+
+impl From<usize> for Size128 {
+    fn from(rhs: usize) -> Self {
+        Self(rhs as u128)
+    }
+}
+
+impl From<Size128> for u128 {
+    fn from(rhs: Size128) -> Self {
+        rhs.0
+    }
+}
+
+impl From<Size> for Size128 {
+    fn from(rhs: Size) -> Self {
+        Self(rhs.0 as u128)
+    }
+}
+
+impl TryFrom<Size128> for Size {
+    type Error = TryFromIntError;
+
+    /// Will error if the size is larger than `Size` can hold.
+    fn try_from(rhs: Size128) -> Result<Self, Self::Error> {
+        Ok(Self(rhs.0.try_into()?))
+    }
+}
+
+impl TryFrom<i128> for Size128 {
+    type Error = TryFromIntError;
+
+    /// Will error if x < 0.
+    fn try_from(rhs: i128) -> Result<Self, Self::Error> {
+        Ok(Self(rhs.try_into()?))
+    }
+}
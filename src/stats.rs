@@ -0,0 +1,232 @@
+use std::fmt::Display;
+
+use crate::{Base, Size, Size128, Units, BINARY_UNITS, DECIMAL_UNITS};
+
+/// Summary statistics over a collection of sizes.
+/// Create these by using `SizeStats::from_sizes`.
+///
+/// The total is a `Size128` so that summing many large sizes can't overflow,
+/// and the mean is computed from it, so it is exact to the byte (rounded down).
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// # if cfg!(feature = "default-binary") { return; }
+/// let files = [300_000u64, 812_000, 2_000_000, 9_000_000].map(Size::from);
+/// let stats = SizeStats::from_sizes(files).unwrap();
+///
+/// println!("{}", stats); // "n=4 total=12.1 MB mean=3.0 MB median=1.4 MB"
+/// # assert_eq!(stats.to_string(), "n=4 total=12.1 MB mean=3.0 MB median=1.4 MB");
+/// assert_eq!(stats.count(), 4);
+/// assert_eq!(stats.min(), Size::from(300_000u64));
+/// assert_eq!(stats.max(), Size::from(9_000_000u64));
+/// assert_eq!(stats.median(), Size::from(1_406_000u64));
+/// # assert_eq!(stats.total(), Size128::from(12_112_000u64));
+/// # assert_eq!(stats.mean(), Size::from(3_028_000u64));
+///
+/// assert!(SizeStats::from_sizes([]).is_none());
+/// # let odd = SizeStats::from_sizes([Size::from(3u64), Size::from(1u64), Size::from(2u64)]).unwrap();
+/// # assert_eq!(odd.median(), Size::from(2u64));
+/// # let huge = SizeStats::from_sizes([Size::MAX, Size::MAX, Size::MAX]).unwrap();
+/// # assert_eq!(huge.mean(), Size::MAX);
+/// # assert_eq!(huge.median(), Size::MAX);
+/// # assert_eq!(huge.total(), Size128::from(3 * u64::MAX as u128));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeStats {
+    count: usize,
+    total: Size128,
+    min: Size,
+    max: Size,
+    mean: Size,
+    median: Size,
+}
+
+impl SizeStats {
+    /// Computes statistics over the given sizes, or returns `None` if there are none.
+    ///
+    /// The sizes are collected and sorted to find the median; with an even count,
+    /// the median is the midpoint of the two middle sizes.
+    pub fn from_sizes(sizes: impl IntoIterator<Item = Size>) -> Option<SizeStats> {
+        let mut sizes: Vec<Size> = sizes.into_iter().collect();
+        if sizes.is_empty() {
+            return None;
+        }
+        sizes.sort_unstable();
+
+        let count = sizes.len();
+        let total: u128 = sizes.iter().map(|size| size.0 as u128).sum();
+        let middle = count / 2;
+        let median = match count % 2 {
+            0 => sizes[middle - 1].midpoint(sizes[middle]),
+            _ => sizes[middle],
+        };
+
+        Some(SizeStats {
+            count,
+            total: Size128::from(total),
+            min: sizes[0],
+            max: sizes[count - 1],
+            // The mean can never be larger than the max, so it always fits.
+            mean: Size((total / count as u128) as u64),
+            median,
+        })
+    }
+
+    /// Returns how many sizes were summarized.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the sum of every size.
+    pub fn total(&self) -> Size128 {
+        self.total
+    }
+
+    /// Returns the smallest size.
+    pub fn min(&self) -> Size {
+        self.min
+    }
+
+    /// Returns the largest size.
+    pub fn max(&self) -> Size {
+        self.max
+    }
+
+    /// Returns the mean size, rounded down to a whole byte.
+    pub fn mean(&self) -> Size {
+        self.mean
+    }
+
+    /// Returns the median size.
+    pub fn median(&self) -> Size {
+        self.median
+    }
+}
+
+impl Display for SizeStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "n={} total={} mean={} median={}",
+            self.count, self.total, self.mean, self.median
+        )
+    }
+}
+
+/// Counts of sizes bucketed by order of magnitude, such as "1 kB - 1 MB".
+/// Create these by using `SizeHistogram::new(Base)`.
+///
+/// Each bucket is keyed by the unit at its lower bound, as returned by
+/// `Size::magnitude_bucket`.
+///
+/// # Usage
+/// ```rust
+/// # use repr_size::*;
+/// let mut histogram = SizeHistogram::new(Base::Decimal);
+/// for bytes in [12u64, 999, 1000, 54222, 3_000_000] {
+///     histogram.add(Size::from(bytes));
+/// }
+///
+/// assert_eq!(histogram.counts()[..3], [
+///     (Units::Bytes, 2),
+///     (Units::Kilobytes, 2),
+///     (Units::Megabytes, 1),
+/// ]);
+/// println!("{}", histogram);
+/// # assert_eq!(histogram.to_string(), "\
+/// # < 1 kB           2
+/// # 1 kB - 1 MB      2
+/// # 1 MB - 1 GB      1
+/// # 1 GB - 1 TB      0
+/// # 1 TB - 1 PB      0
+/// # 1 PB - 1 EB      0
+/// # >= 1 EB          0
+/// # ");
+/// # let mut binary = SizeHistogram::new(Base::Binary);
+/// # for bytes in [1000u64, 1023, 1024, 1u64 << 60] {
+/// #     binary.add(Size::from(bytes));
+/// # }
+/// # assert_eq!(binary.counts(), [
+/// #     (Units::Bytes, 2),
+/// #     (Units::Kibibytes, 1),
+/// #     (Units::Mebibytes, 0),
+/// #     (Units::Gibibytes, 0),
+/// #     (Units::Tebibytes, 0),
+/// #     (Units::Pebibytes, 0),
+/// #     (Units::Exbibytes, 1),
+/// # ]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizeHistogram {
+    base: Base,
+    counts: Vec<(Units, u64)>,
+}
+
+impl SizeHistogram {
+    /// Creates an empty histogram with one bucket per unit of the given base.
+    pub fn new(base: Base) -> SizeHistogram {
+        let units = match base {
+            Base::Decimal => DECIMAL_UNITS,
+            Base::Binary => BINARY_UNITS,
+        };
+        SizeHistogram {
+            base,
+            counts: units.iter().map(|unit| (*unit, 0)).collect(),
+        }
+    }
+
+    /// Counts a size in the bucket for its order of magnitude.
+    pub fn add(&mut self, size: Size) {
+        let bucket = size.magnitude_bucket(self.base);
+        if let Some((_, count)) = self.counts.iter_mut().find(|(unit, _)| *unit == bucket) {
+            *count += 1;
+        }
+    }
+
+    /// Returns the count of sizes in each bucket, smallest bucket first.
+    pub fn counts(&self) -> &[(Units, u64)] {
+        &self.counts
+    }
+}
+
+impl Extend<Size> for SizeHistogram {
+    fn extend<I: IntoIterator<Item = Size>>(&mut self, iter: I) {
+        for size in iter {
+            self.add(size);
+        }
+    }
+}
+
+impl Display for SizeHistogram {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let last = self.counts.len() - 1;
+        for (i, (unit, count)) in self.counts.iter().enumerate() {
+            let label = match i {
+                0 => format!("< 1 {}", self.counts[1].0),
+                i if i == last => format!(">= 1 {}", unit),
+                i => format!("1 {} - 1 {}", unit, self.counts[i + 1].0),
+            };
+            writeln!(f, "{:<16} {}", label, count)?;
+        }
+        Ok(())
+    }
+}
+
+impl Size {
+    /// Returns the unit at the lower bound of this size's order of magnitude,
+    /// which is the same unit `get_units_in` would pick.
+    ///
+    /// # Usage
+    /// ```rust
+    /// # use repr_size::*;
+    /// assert_eq!(Size::from(1000u64).magnitude_bucket(Base::Decimal), Units::Kilobytes);
+    /// assert_eq!(Size::from(1000u64).magnitude_bucket(Base::Binary), Units::Bytes);
+    /// assert_eq!(Size::from(1024u64).magnitude_bucket(Base::Binary), Units::Kibibytes);
+    /// # assert_eq!(Size::from(999u64).magnitude_bucket(Base::Decimal), Units::Bytes);
+    /// # assert_eq!(Size::from(1023u64).magnitude_bucket(Base::Binary), Units::Bytes);
+    /// ```
+    pub const fn magnitude_bucket(&self, base: Base) -> Units {
+        self.get_units_in(base)
+    }
+}
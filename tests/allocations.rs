@@ -0,0 +1,81 @@
+//! Checks that formatting and parsing don't allocate, by counting the
+//! allocations each test thread makes.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Write;
+
+use repr_size::*;
+
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Returns how many allocations `f` made on this thread.
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn writing_does_not_allocate() {
+    let mut line = String::with_capacity(256);
+    for bytes in [0u64, 999, 54_222, 1_048_576, u64::MAX] {
+        let size = Size::from(bytes);
+        let written = allocations(|| {
+            write!(line, "{:>10.2} {:#} {:+}", size, size, size).unwrap();
+            write!(line, "{} {}", size.display(), size.display_as(Units::Kibibytes)).unwrap();
+            write!(line, "{}", SizeFormatter::new().tabular(12).display(size)).unwrap();
+            write!(line, "{}", SizeDelta::from(size)).unwrap();
+        });
+        assert_eq!(written, 0, "{} bytes", bytes);
+        line.clear();
+    }
+}
+
+#[test]
+fn to_string_allocates_once() {
+    for bytes in [0u64, 999, 54_222, 1_048_576, u64::MAX] {
+        assert_eq!(allocations(|| drop(Size::from(bytes).to_string())), 1, "{} bytes", bytes);
+    }
+}
+
+#[test]
+fn parsing_does_not_allocate() {
+    let parsed = allocations(|| {
+        let mut rest = "limit>=2GiB && 1,048,576 B < 0x10 MiB || 1.2.3";
+        let mut sizes = 0;
+        while !rest.is_empty() {
+            match Size::parse_prefix(rest) {
+                Ok((_, tail)) => {
+                    sizes += 1;
+                    rest = tail;
+                }
+                Err(_) => rest = &rest[1..],
+            }
+        }
+        assert_eq!(sizes, 5);
+        let _ = Units::parse_prefix("GiB && x");
+        let _ = SizeParser::lenient().parse_prefix("2 gigs && x");
+        let _ = Size::parse_prefix("17 EiB");
+        let _ = "54.2 kB".parse::<Size>();
+    });
+    assert_eq!(parsed, 0);
+}
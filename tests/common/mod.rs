@@ -0,0 +1,13 @@
+//! Helpers shared by the tests and benches.
+
+use repr_size::Units;
+
+/// Picks a unit the way `Size` did before it used `ilog`: walks down from the
+/// largest of `units` until one fits in `bytes`.
+pub fn ladder(units: &[Units], bytes: u128) -> Units {
+    let mut i = units.len() - 1;
+    while i > 0 && bytes < units[i].bytes() as u128 {
+        i -= 1;
+    }
+    units[i]
+}
@@ -0,0 +1,256 @@
+//! Checks that hold for every size, run over the same pseudo-random sample.
+
+use repr_size::*;
+
+mod common;
+
+use common::ladder;
+
+/// The edge cases, then 10,000 sizes spread over every magnitude: xorshift64
+/// output shifted right by a varying amount, so small sizes are as common as large ones.
+fn sizes() -> impl Iterator<Item = Size> {
+    let edges = [0u64, 1, 999, 1000, 1023, 1024, u64::MAX - 1, u64::MAX];
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let random = std::iter::repeat_with(move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state >> (state % 64)
+    });
+    edges.into_iter().chain(random.take(10_000)).map(Size::from)
+}
+
+/// Byte counts on and around every boundary between units in either base, and
+/// around every power of two and of ten.
+fn unit_boundaries() -> Vec<u64> {
+    let mut sweep = vec![0u64, 1, 2, u64::MAX - 1, u64::MAX];
+    for step in [1000u64, 1024] {
+        for power in 1..=6 {
+            let edge = step.pow(power);
+            sweep.extend([edge - 2, edge - 1, edge, edge + 1, edge + 2, edge * 2 - 1]);
+        }
+    }
+    for shift in 0..64 {
+        sweep.extend([1u64 << shift, (1u64 << shift) - 1]);
+    }
+    let mut power_of_ten = 1u64;
+    while let Some(next) = power_of_ten.checked_mul(10) {
+        sweep.extend([power_of_ten - 1, power_of_ten, power_of_ten + 1]);
+        power_of_ten = next;
+    }
+    sweep
+}
+
+#[test]
+fn unit_selection_matches_ladder() {
+    for size in unit_boundaries().into_iter().map(Size::from).chain(sizes()) {
+        let bytes = size.bytes() as u128;
+        assert_eq!(size.get_units_in(Base::Decimal), ladder(Units::decimal(), bytes), "{:?}", size);
+        assert_eq!(size.get_units_in(Base::Binary), ladder(Units::binary(), bytes), "{:?}", size);
+        assert_eq!(size.get_units_in(Base::Decimal), size.get_decimal_unit());
+        assert_eq!(size.get_units_in(Base::Binary), size.get_binary_unit());
+        assert_eq!(size.humanize(Base::Decimal), size.to_decimal_string());
+        assert_eq!(size.humanize(Base::Binary), size.to_binary_string());
+    }
+}
+
+#[test]
+fn size128_unit_selection_matches_ladder() {
+    for shift in 0..128 {
+        for bytes in [1u128 << shift, (1u128 << shift) - 1, (1u128 << shift) + 1] {
+            let size = Size128::from(bytes);
+            assert_eq!(size.get_decimal_unit(), ladder(Units::decimal(), bytes), "{} bytes", bytes);
+            assert_eq!(size.get_binary_unit(), ladder(Units::binary(), bytes), "{} bytes", bytes);
+            if let Ok(small) = Size::try_from(size) {
+                assert_eq!(size.get_decimal_unit(), small.get_decimal_unit());
+                assert_eq!(size.get_binary_unit(), small.get_binary_unit());
+            }
+        }
+    }
+}
+
+/// Pairs of sizes from `sizes`, each with the one after it.
+fn size_pairs() -> impl Iterator<Item = (Size, Size)> {
+    sizes().zip(sizes().skip(1))
+}
+
+#[test]
+fn midpoint_is_the_rounded_down_average() {
+    for (a, b) in size_pairs() {
+        let average = (a.bytes() as u128 + b.bytes() as u128) / 2;
+        assert_eq!(a.midpoint(b).bytes() as u128, average, "{:?} and {:?}", a, b);
+        assert_eq!(a.midpoint(b), b.midpoint(a));
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn lerp_stays_between_the_endpoints() {
+    for (a, b) in size_pairs() {
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        for t in [0.001, 0.25, 0.5, 0.75, 0.999] {
+            let between = a.lerp(b, t);
+            assert!(a.min(b) <= between && between <= a.max(b), "{:?} and {:?} at {}", a, b, t);
+        }
+    }
+}
+
+#[test]
+fn exact_string_parses_back() {
+    for size in sizes() {
+        assert_eq!(size.to_exact_string(), format!("{} B", size.bytes()));
+        assert_eq!(size.to_exact_string().parse::<Size>(), Ok(size));
+    }
+}
+
+#[test]
+fn humanized_parses_back_within_rounding() {
+    for size in sizes() {
+        for base in [Base::Decimal, Base::Binary] {
+            let text = size.humanize(base);
+            let unit = size.get_units_in(base);
+            match text.parse::<Size>() {
+                // One decimal is off by at most half a tenth of the unit, plus the byte
+                // parsing rounds to.
+                Ok(parsed) => assert!(parsed.abs_diff(size).bytes() <= unit.bytes() / 20 + 1, "{} from {:?}", text, size),
+                // Sizes that round up to 16.0 EiB are past `Size::MAX`.
+                Err(err) => assert_eq!((err, text.as_str()), (ParseSizeError::Overflow, "16.0 EiB")),
+            }
+        }
+    }
+}
+
+#[test]
+fn jedec_string_is_a_fixpoint() {
+    let compatible = SizeParser::new().decimal_suffix_base(Base::Binary);
+    for size in sizes() {
+        let shown = size.to_jedec_string();
+        match compatible.parse(&shown) {
+            Ok(parsed) => assert_eq!(parsed.to_jedec_string(), shown),
+            // Rounded up past the largest size.
+            Err(err) => assert_eq!((err, shown.as_str()), (ParseSizeError::Overflow, "16.0 EB")),
+        }
+    }
+}
+
+#[test]
+fn breakdown_parses_back() {
+    for size in sizes() {
+        for base in [Base::Decimal, Base::Binary] {
+            assert_eq!(Size::parse_sum(&size.to_breakdown_string(base)), Ok(size));
+        }
+    }
+}
+
+#[test]
+fn default_formatter_matches_strings() {
+    for size in sizes() {
+        assert_eq!(SizeFormatter::new().format(size), size.to_decimal_string());
+        assert_eq!(SizeFormatter::new().display(size).to_string(), size.to_decimal_string());
+        assert_eq!(SizeFormatter::new().base(Base::Binary).format(size), size.to_binary_string());
+    }
+}
+
+#[test]
+fn ceil_and_floor_bound_the_size() {
+    for size in sizes().take(2000) {
+        for unit in Units::ALL {
+            for decimals in 0..4 {
+                // Compares the number shown against the size, both scaled by 10^decimals.
+                let compare = |mode| {
+                    let repr = size.repr_with_rounding(*unit, decimals, mode);
+                    let number = repr.split(' ').next().unwrap();
+                    let shown_decimals = number.split('.').nth(1).map_or(0, str::len);
+                    let shown = number.replace('.', "").parse::<u128>().unwrap() * unit.bytes() as u128;
+                    shown.cmp(&(size.bytes() as u128 * 10u128.pow(shown_decimals as u32)))
+                };
+                assert!(compare(Rounding::Ceil).is_ge(), "{:?}", size);
+                assert!(compare(Rounding::Floor).is_le(), "{:?}", size);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "float")]
+#[test]
+fn repr_matches_float_division() {
+    // Below 2^49 bytes an f64 is exact enough to check against, away from ties.
+    for size in sizes().filter(|size| (1..1 << 49).contains(&size.bytes())) {
+        for unit in Units::ALL {
+            let unit_bytes = unit.bytes() as u128;
+            if (size.bytes() as u128 * 20) % (2 * unit_bytes) == unit_bytes {
+                continue;
+            }
+            let float = match unit {
+                Units::Bytes => format!("{} B", size.bytes()),
+                _ => format!("{:.1} {}", size.bytes() as f64 / unit_bytes as f64, unit),
+            };
+            assert_eq!(size.repr(*unit), float, "{:?}", size);
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use repr_size::*;
+    use serde::{Deserialize, Serialize};
+
+    use super::sizes;
+
+    #[test]
+    fn string_round_trips() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Config {
+            #[serde(with = "repr_size::serde_string")]
+            max_upload: Size,
+        }
+
+        for size in sizes() {
+            let config = Config { max_upload: size };
+            let json = serde_json::to_string(&config).unwrap();
+            assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+        }
+    }
+
+    #[test]
+    fn string_round_trips_through_toml() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Config {
+            #[serde(with = "repr_size::serde_string")]
+            max_upload: Size,
+            #[serde(with = "repr_size::serde_string_option", default)]
+            quota: Option<Size>,
+        }
+
+        let config: Config = toml::from_str("max_upload = \"25 MiB\"\nquota = \"2 GiB\"").unwrap();
+        assert_eq!(config, Config { max_upload: Size::MIB * 25, quota: Some(Size::GIB * 2) });
+        assert_eq!(toml::to_string(&config).unwrap(), "max_upload = \"25 MiB\"\nquota = \"2 GiB\"\n");
+
+        // TOML has no null, so a missing quota is the only way to write `None`.
+        let config: Config = toml::from_str("max_upload = \"25 MiB\"").unwrap();
+        assert_eq!(config.quota, None);
+        assert_eq!(toml::to_string(&config).unwrap(), "max_upload = \"25 MiB\"\n");
+
+        for (i, size) in sizes().enumerate() {
+            let config = Config { max_upload: size, quota: (i % 2 == 0).then_some(size) };
+            let text = toml::to_string(&config).unwrap();
+            assert_eq!(toml::from_str::<Config>(&text).unwrap(), config, "{}", text);
+        }
+    }
+
+    #[test]
+    fn struct_round_trips() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Volume {
+            #[serde(with = "repr_size::serde_struct")]
+            capacity: Size,
+        }
+
+        for size in sizes() {
+            let volume = Volume { capacity: size };
+            let json = serde_json::to_string(&volume).unwrap();
+            assert_eq!(serde_json::from_str::<Volume>(&json).unwrap(), volume);
+        }
+    }
+}